@@ -0,0 +1,39 @@
+//! A 2D camera composed into an `Affine2` through `begin`/`and_then`,
+//! the same way the 3D examples compose into a `Mat4`. Run with:
+//! `cargo run --example camera_2d`
+use glam::{Affine2, Vec2};
+use buttery::{Angle, Scaffold, TransformComponent, Translate};
+
+struct Camera {
+    position: TransformComponent<Translate<Vec2>>,
+    rotation: TransformComponent<Angle>,
+    zoom: TransformComponent<Translate<f32>>,
+}
+
+fn main() {
+    let mut camera = Camera {
+        position: TransformComponent::new_translate(Vec2::ZERO),
+        // Takes the shortest way around the circle.
+        rotation: TransformComponent::new_angle_wrapped(0.1),
+        zoom: TransformComponent::new_zoom(1.0),
+    };
+
+    // Simulate user input:
+    camera.position.target = Vec2::new(10.0, -4.0);
+    camera.rotation.target = 6.2;
+    camera.zoom.target = 2.0;
+
+    for frame in 0..60 {
+        // Each stage applies on the outside of the previous ones, so this
+        // scales, then rotates, then translates.
+        let transform = camera.zoom.begin(|zoom| Affine2::from_scale(Vec2::splat(zoom)))
+            .and_then(&mut camera.rotation, Affine2::from_angle)
+            .and_then(&mut camera.position, Affine2::from_translation)
+            .drive(0.016);
+
+        let view = transform.inverse();
+        if frame % 10 == 0 {
+            println!("frame {frame}: origin at {}", view.transform_point2(Vec2::ZERO));
+        }
+    }
+}
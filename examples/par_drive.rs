@@ -0,0 +1,42 @@
+//! Compares driving a million components serially and through
+//! `par_drive_all`. Run with:
+//! `cargo run --release --example par_drive --features parallel`
+use std::time::Instant;
+use glam::Vec3;
+use buttery::{par_drive_all, TransformComponent, Translate};
+
+const COUNT: usize = 1_000_000;
+const FRAMES: u32 = 20;
+
+fn components() -> Vec<TransformComponent<Translate<Vec3>>> {
+    (0..COUNT)
+        .map(|i| {
+            let mut component = TransformComponent::new_translate(Vec3::ZERO);
+            component.target = Vec3::splat(i as f32);
+            component
+        })
+        .collect()
+}
+
+fn main() {
+    let mut serial = components();
+    let start = Instant::now();
+    for _ in 0..FRAMES {
+        for component in &mut serial {
+            component.drive(0.016);
+        }
+    }
+    let serial_time = start.elapsed() / FRAMES;
+
+    let mut parallel = components();
+    let start = Instant::now();
+    for _ in 0..FRAMES {
+        par_drive_all(&mut parallel, 0.016);
+    }
+    let parallel_time = start.elapsed() / FRAMES;
+
+    assert!(serial.iter().zip(&parallel).all(|(a, b)| a.current == b.current));
+    println!("{COUNT} components per frame:");
+    println!("  serial:   {serial_time:?}");
+    println!("  parallel: {parallel_time:?}");
+}
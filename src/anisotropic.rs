@@ -0,0 +1,74 @@
+use glam::Vec3;
+
+/// A smoothed translation with a separate retention for each axis, for
+/// example a platformer camera which follows horizontally more tightly
+/// than vertically so small jumps don't bob the view.
+///
+/// Each axis behaves exactly like a
+/// [`TransformComponent<Translate<f32>>`](crate::TransformComponent) with
+/// the matching retention, so `retention.x` keeps that fraction of the
+/// remaining x distance every second.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TranslateAnisotropic {
+    /// How close each axis of the current value follows the target.
+    pub retention: Vec3,
+    /// The current value.
+    pub current: Vec3,
+    /// The target value.
+    pub target: Vec3,
+}
+
+impl TranslateAnisotropic {
+    /// Creates a new `TranslateAnisotropic` resting at `initial`.
+    pub const fn new(retention: Vec3, initial: Vec3) -> Self {
+        Self {
+            retention,
+            current: initial,
+            target: initial,
+        }
+    }
+
+    /// Whether `current` has come within `epsilon` of `target`.
+    pub fn is_settled(&self, epsilon: f32) -> bool {
+        self.current.distance(self.target) <= epsilon
+    }
+
+    /// Drives each axis towards the target by `delta_time` seconds,
+    /// using its own retention.
+    pub fn drive(&mut self, delta_time: f32) -> Vec3 {
+        let kept = Vec3::new(
+            self.retention.x.powf(delta_time),
+            self.retention.y.powf(delta_time),
+            self.retention.z.powf(delta_time),
+        );
+        let percent = (Vec3::ONE - kept).clamp(Vec3::ZERO, Vec3::ONE);
+        self.current += (self.target - self.current) * percent;
+        self.current
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{TransformComponent, Translate};
+    use super::*;
+
+    #[test]
+    fn axes_follow_independently() {
+        let mut camera = TranslateAnisotropic::new(Vec3::new(0.001, 0.3, 0.001), Vec3::ZERO);
+        let mut horizontal = TransformComponent::<Translate<f32>>::new(0.001, 0.0);
+        let mut vertical = TransformComponent::<Translate<f32>>::new(0.3, 0.0);
+        camera.target = Vec3::new(2.0, 2.0, 0.0);
+        horizontal.target = 2.0;
+        vertical.target = 2.0;
+
+        for _ in 0..10 {
+            camera.drive(0.016);
+            horizontal.drive(0.016);
+            vertical.drive(0.016);
+        }
+        assert_eq!(camera.current.x, horizontal.current);
+        assert_eq!(camera.current.y, vertical.current);
+        assert!(camera.current.x > camera.current.y * 2.0);
+        assert_eq!(camera.current.z, 0.0);
+    }
+}
@@ -0,0 +1,99 @@
+use core::fmt::Formatter;
+use crate::{Smoothed, TransformComponent};
+
+/// Smooths a component with separate attack and release retentions, such
+/// as for a meter which should jump up quickly but fall back slowly.
+///
+/// The attack retention applies while the target pulls away from the
+/// current value, and the release retention once it comes back towards it.
+/// This is detected by comparing the distance to the target at the start of
+/// each drive with the distance at the end of the previous one, so only the
+/// target's motion counts, not the component catching up. While the target
+/// stands still the previous choice is kept.
+pub struct Asymmetric<T: Smoothed> {
+    /// The smoothed component, whose `retention` is overwritten each drive.
+    pub component: TransformComponent<T>,
+    /// The retention while the target pulls away.
+    pub attack: T::Scalar,
+    /// The retention while the target comes back.
+    pub release: T::Scalar,
+    last_distance: f32,
+    attacking: bool,
+}
+
+impl<T: Smoothed> core::fmt::Debug for Asymmetric<T> where
+    T::Attribute: core::fmt::Debug {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Asymmetric")
+            .field("component", &self.component)
+            .field("attack", &self.attack)
+            .field("release", &self.release)
+            .field("attacking", &self.attacking)
+            .finish()
+    }
+}
+
+impl<T: Smoothed> Asymmetric<T> {
+    /// Creates a new `Asymmetric` resting at `initial`.
+    pub fn new(attack: T::Scalar, release: T::Scalar, initial: T::Attribute) -> Self {
+        Self {
+            component: TransformComponent::new(attack, initial),
+            attack,
+            release,
+            last_distance: 0.0,
+            attacking: true,
+        }
+    }
+
+    /// Whether the attack retention was used by the most recent drive.
+    pub fn is_attacking(&self) -> bool {
+        self.attacking
+    }
+
+    /// Picks the attack or release retention, and drives the component
+    /// by `delta_time` seconds.
+    pub fn drive(&mut self, delta_time: f32) -> T::Attribute {
+        let distance = T::distance(self.component.current, self.component.target);
+        if distance > self.last_distance {
+            self.attacking = true;
+        } else if distance < self.last_distance {
+            self.attacking = false;
+        }
+
+        self.component.retention = if self.attacking { self.attack } else { self.release };
+        let driven = self.component.drive(delta_time);
+        self.last_distance = T::distance(driven, self.component.target);
+        driven
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Translate;
+    use super::*;
+
+    #[test]
+    fn attack_is_faster_than_release() {
+        let mut meter = Asymmetric::<Translate<f32>>::new(0.001, 0.5, 0.0);
+
+        // The target rises away from the meter.
+        meter.component.target = 1.0;
+        let before = meter.component.current;
+        let rise = meter.drive(0.016) - before;
+        assert!(meter.is_attacking());
+
+        // Standing still keeps attacking while the meter catches up.
+        meter.drive(0.016);
+        assert!(meter.is_attacking());
+
+        // The target falls back towards the meter.
+        meter.component.target = meter.component.current + 0.5;
+        let before = meter.component.current;
+        let fall = meter.drive(0.016) - before;
+        assert!(!meter.is_attacking());
+
+        let rise_fraction = rise / 1.0;
+        let fall_fraction = fall / 0.5;
+        assert!(rise_fraction > fall_fraction * 5.0);
+    }
+}
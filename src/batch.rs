@@ -0,0 +1,64 @@
+use std::num::NonZeroUsize;
+use std::thread;
+use crate::{Smoothed, TransformComponent};
+
+/// Below this many components per thread, spawning threads costs more
+/// than it saves.
+const MIN_CHUNK: usize = 16 * 1024;
+
+/// Drives every component in `components` by `delta_time` seconds,
+/// splitting the slice across all available threads.
+///
+/// Components are independent of each other, so the results are identical
+/// to driving them one by one in a loop. Small slices are driven on the
+/// calling thread, and larger ones are split into one contiguous chunk per
+/// thread of at least 16384 components.
+pub fn par_drive_all<T: Smoothed>(components: &mut [TransformComponent<T>], delta_time: f32)
+where TransformComponent<T>: Send {
+    let threads = thread::available_parallelism().map_or(1, NonZeroUsize::get);
+    let chunk = components.len().div_ceil(threads).max(MIN_CHUNK);
+    if chunk >= components.len() {
+        components.iter_mut().for_each(|component| {
+            component.drive(delta_time);
+        });
+        return;
+    }
+
+    thread::scope(|scope| {
+        for chunk in components.chunks_mut(chunk) {
+            scope.spawn(move || {
+                chunk.iter_mut().for_each(|component| {
+                    component.drive(delta_time);
+                });
+            });
+        }
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use glam::Vec3;
+    use crate::Translate;
+    use super::*;
+
+    #[test]
+    fn matches_serial_drive() {
+        let mut parallel: Vec<_> = (0..100_000)
+            .map(|i| {
+                let mut component = TransformComponent::<Translate<Vec3>>::new(0.05, Vec3::ZERO);
+                component.target = Vec3::new(i as f32, -(i as f32) * 0.5, 1.0);
+                component
+            })
+            .collect();
+        let mut serial = parallel.clone();
+
+        for _ in 0..3 {
+            par_drive_all(&mut parallel, 0.016);
+            for component in &mut serial {
+                component.drive(0.016);
+            }
+        }
+
+        assert!(parallel.iter().zip(&serial).all(|(a, b)| a.current == b.current));
+    }
+}
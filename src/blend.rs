@@ -0,0 +1,171 @@
+use glam::Mat4;
+
+/// How to blend between two transformation matrices.
+///
+/// This is the trade-off between speed and quality shared by every API
+/// which blends whole matrices.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum BlendMode {
+    /// Lerps each element of the matrices.
+    ///
+    /// This is cheap and exact for pure translations and scales, but blending
+    /// between rotations shrinks and skews the result on the way.
+    Matrix,
+    /// Decomposes both matrices into scale, rotation and translation, lerps
+    /// the scales and translations, slerps the rotations and recomposes them.
+    ///
+    /// This keeps rotations rigid throughout. Both matrices must be affine
+    /// without shear, see [`Mat4::to_scale_rotation_translation`].
+    #[default]
+    Trs,
+}
+
+impl BlendMode {
+    /// Blends from `a` to `b` by `t`, where `0.0` yields `a` and `1.0` yields `b`.
+    pub fn blend(self, a: Mat4, b: Mat4, t: f32) -> Mat4 {
+        match self {
+            BlendMode::Matrix => a + (b - a) * t,
+            BlendMode::Trs => {
+                let (scale_a, rotation_a, translation_a) = a.to_scale_rotation_translation();
+                let (scale_b, rotation_b, translation_b) = b.to_scale_rotation_translation();
+                Mat4::from_scale_rotation_translation(
+                    scale_a.lerp(scale_b, t),
+                    rotation_a.slerp(rotation_b, t),
+                    translation_a.lerp(translation_b, t),
+                )
+            }
+        }
+    }
+}
+
+/// Crossfades from one chain of transformations to another over `duration`
+/// seconds, for example when switching camera modes.
+///
+/// Each chain is a closure which is handed the frame's delta time, and
+/// builds and drives its chain. Both chains are driven every frame so that
+/// each stays up to date, and their results are blended through [`BlendMode`].
+/// ```
+/// # use glam::{Mat4, Vec3};
+/// # use buttery::{BlendMode, CrossfadeChain, Scaffold, TransformComponent};
+/// let mut orbit_position = TransformComponent::new_translate(Vec3::new(0.0, 5.0, 10.0));
+/// let mut eye_position = TransformComponent::new_translate(Vec3::new(0.0, 1.8, 0.0));
+///
+/// let mut crossfade = CrossfadeChain::new(
+///     move |delta_time| orbit_position.begin(Mat4::from_translation).drive(delta_time),
+///     move |delta_time| eye_position.begin(Mat4::from_translation).drive(delta_time),
+///     0.5,
+///     BlendMode::Trs,
+/// );
+///
+/// while !crossfade.is_finished() {
+///     let camera = crossfade.drive(0.016);
+///     # assert!(camera.is_finite());
+/// }
+/// ```
+pub struct CrossfadeChain<A, B> {
+    a: A,
+    b: B,
+    /// How long the crossfade takes, in seconds.
+    pub duration: f32,
+    /// How the two chains are blended.
+    pub mode: BlendMode,
+    progress: f32,
+}
+
+impl<A: FnMut(f32) -> Mat4, B: FnMut(f32) -> Mat4> CrossfadeChain<A, B> {
+    /// Creates a new crossfade which starts out fully showing `a`, and
+    /// fades over to `b` within `duration` seconds.
+    pub fn new(a: A, b: B, duration: f32, mode: BlendMode) -> Self {
+        Self {
+            a,
+            b,
+            duration,
+            mode,
+            progress: 0.0,
+        }
+    }
+
+    /// How far along the crossfade is, from `0.0` fully showing the first
+    /// chain to `1.0` fully showing the second.
+    pub fn progress(&self) -> f32 {
+        self.progress
+    }
+
+    /// Whether the crossfade has fully arrived at the second chain.
+    pub fn is_finished(&self) -> bool {
+        self.progress >= 1.0
+    }
+
+    /// Starts the crossfade over from the first chain.
+    pub fn restart(&mut self) {
+        self.progress = 0.0;
+    }
+
+    /// Advances the crossfade by `delta_time` seconds, drives both chains,
+    /// and blends their results.
+    pub fn drive(&mut self, delta_time: f32) -> Mat4 {
+        self.progress = if self.duration > 0.0 {
+            (self.progress + delta_time / self.duration).clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+
+        let a = (self.a)(delta_time);
+        let b = (self.b)(delta_time);
+        self.mode.blend(a, b, self.progress)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use glam::{Mat3, Quat, Vec3};
+    use crate::{Scaffold, TransformComponent};
+    use super::*;
+
+    #[test]
+    fn rotation_heavy_blend() {
+        let a = Mat4::from_rotation_translation(Quat::IDENTITY, Vec3::X);
+        let b = Mat4::from_rotation_translation(Quat::from_rotation_y(3.0), Vec3::Z);
+
+        for mode in [BlendMode::Matrix, BlendMode::Trs] {
+            assert!(mode.blend(a, b, 0.0).abs_diff_eq(a, 1e-5));
+            assert!(mode.blend(a, b, 1.0).abs_diff_eq(b, 1e-5));
+        }
+
+        let cheap = Mat3::from_mat4(BlendMode::Matrix.blend(a, b, 0.5));
+        let rigid = Mat3::from_mat4(BlendMode::Trs.blend(a, b, 0.5));
+
+        // Halfway between two nearly opposite rotations, the element-wise
+        // lerp nearly collapses while the decomposed blend stays a rotation.
+        assert!(cheap.determinant() < 0.1);
+        assert!((rigid.determinant() - 1.0).abs() < 1e-5);
+        assert!(rigid.x_axis.is_normalized());
+        assert!(rigid.mul_mat3(&rigid.transpose()).abs_diff_eq(Mat3::IDENTITY, 1e-5));
+        assert!(BlendMode::Trs.blend(a, b, 0.5).w_axis.truncate().abs_diff_eq(Vec3::new(0.5, 0.0, 0.5), 1e-5));
+    }
+
+    #[test]
+    fn crossfade_endpoints() {
+        let mut first = TransformComponent::new_rotate(Quat::from_rotation_y(1.0));
+        let mut second = TransformComponent::new_translate(Vec3::new(0.0, 3.0, 0.0));
+        let mut crossfade = CrossfadeChain::new(
+            move |delta_time| first.begin(Mat4::from_quat).drive(delta_time),
+            move |delta_time| second.begin(Mat4::from_translation).drive(delta_time),
+            0.1,
+            BlendMode::Trs,
+        );
+
+        assert_eq!(crossfade.progress(), 0.0);
+        assert!(crossfade.drive(0.0).abs_diff_eq(Mat4::from_rotation_y(1.0), 1e-5));
+
+        let halfway = crossfade.drive(0.05);
+        assert!((crossfade.progress() - 0.5).abs() < 1e-6);
+        assert!(!crossfade.is_finished());
+        assert!(halfway.w_axis.truncate().abs_diff_eq(Vec3::new(0.0, 1.5, 0.0), 1e-5));
+
+        crossfade.drive(0.08);
+        assert!(crossfade.is_finished());
+        assert_eq!(crossfade.progress(), 1.0);
+        assert!(crossfade.drive(0.016).abs_diff_eq(Mat4::from_translation(Vec3::new(0.0, 3.0, 0.0)), 1e-5));
+    }
+}
@@ -0,0 +1,158 @@
+use core::fmt::Formatter;
+use glam::{DVec2, DVec3, DVec4, Vec2, Vec3, Vec3A, Vec4};
+use crate::{Smoothed, TransformComponent};
+
+/// Attributes which can be clamped between a minimum and a maximum, per
+/// component for vectors.
+pub trait Clamp: Copy {
+    /// Clamps `self` into `[min, max]`.
+    ///
+    /// Unlike `f32::clamp` this never panics: with `min > max` the result
+    /// is `max`, and a NaN bound is ignored.
+    fn clamp_within(self, min: Self, max: Self) -> Self;
+
+    /// Whether `min` is no greater than `max`, in every component, with
+    /// neither being NaN.
+    fn is_ordered(min: Self, max: Self) -> bool;
+}
+
+macro_rules! impl_clamp {
+    (scalar: $($scalar:ty),*; vector: $($vector:ty),*) => {
+        $(
+            impl Clamp for $scalar {
+                #[inline(always)]
+                fn clamp_within(self, min: Self, max: Self) -> Self {
+                    self.max(min).min(max)
+                }
+                #[inline(always)]
+                fn is_ordered(min: Self, max: Self) -> bool {
+                    min <= max
+                }
+            }
+        )*
+        $(
+            impl Clamp for $vector {
+                #[inline(always)]
+                fn clamp_within(self, min: Self, max: Self) -> Self {
+                    self.max(min).min(max)
+                }
+                #[inline(always)]
+                fn is_ordered(min: Self, max: Self) -> bool {
+                    min.cmple(max).all()
+                }
+            }
+        )*
+    };
+}
+
+impl_clamp!(scalar: f32, f64; vector: Vec2, Vec3, Vec3A, Vec4, DVec2, DVec3, DVec4);
+
+/// A component whose `target` and `current` are kept within `[min, max]`,
+/// such as a zoom with hard limits or a pan inside a bounding box.
+///
+/// Both are clamped on every drive, so a target set out of bounds, or a
+/// `current` nudged out of bounds, never shows through. Vectors are
+/// clamped per component.
+/// ```
+/// # use buttery::TransformComponent;
+/// let mut zoom = TransformComponent::new_zoom(1.0_f32).with_bounds(0.5, 20.0);
+/// zoom.component.target = 100.0;
+///
+/// for _ in 0..100 {
+///     assert!(zoom.drive(0.016) <= 20.0);
+/// }
+/// ```
+pub struct Bounded<T: Smoothed> where T::Attribute: Clamp {
+    /// The clamped component.
+    pub component: TransformComponent<T>,
+    /// The smallest allowed value.
+    pub min: T::Attribute,
+    /// The largest allowed value.
+    pub max: T::Attribute,
+}
+
+impl<T: Smoothed> core::fmt::Debug for Bounded<T> where
+    T::Attribute: Clamp + core::fmt::Debug {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Bounded")
+            .field("component", &self.component)
+            .field("min", &self.min)
+            .field("max", &self.max)
+            .finish()
+    }
+}
+
+impl<T: Smoothed> Bounded<T> where T::Attribute: Clamp {
+    /// Creates a new `Bounded`, clamping `component` into `[min, max]`.
+    ///
+    /// `min` must not be greater than `max` in any component, which is
+    /// asserted in debug builds. Bounds written out of order later don't
+    /// panic while driving; `max` wins.
+    pub fn new(mut component: TransformComponent<T>, min: T::Attribute, max: T::Attribute) -> Self {
+        debug_assert!(T::Attribute::is_ordered(min, max), "Bounded requires min <= max");
+        component.target = component.target.clamp_within(min, max);
+        component.current = component.current.clamp_within(min, max);
+        Self { component, min, max }
+    }
+
+    /// Clamps `target` and `current`, and drives the component by
+    /// `delta_time` seconds.
+    pub fn drive(&mut self, delta_time: f32) -> T::Attribute {
+        let (min, max) = (self.min, self.max);
+        self.component.target = self.component.target.clamp_within(min, max);
+        self.component.current = self.component.current.clamp_within(min, max);
+        self.component.drive_bounded(delta_time, |value| value.clamp_within(min, max))
+    }
+}
+
+impl<T: Smoothed> TransformComponent<T> where T::Attribute: Clamp {
+    /// Keeps this component within `[min, max]`, see [`Bounded`].
+    pub fn with_bounds(self, min: T::Attribute, max: T::Attribute) -> Bounded<T> {
+        Bounded::new(self, min, max)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use glam::Vec3;
+    use super::*;
+
+    #[test]
+    fn clamps_per_component() {
+        let mut pan = TransformComponent::new_translate(Vec3::ZERO)
+            .with_bounds(Vec3::splat(-1.0), Vec3::splat(1.0));
+        pan.component.target = Vec3::new(5.0, 0.5, -5.0);
+
+        let mut driven = Vec3::ZERO;
+        for _ in 0..500 {
+            driven = pan.drive(0.016);
+        }
+        assert!((driven - Vec3::new(1.0, 0.5, -1.0)).length() < 1e-3);
+
+        // A nudge out of bounds is pulled back in on the next drive.
+        pan.component.current = Vec3::splat(10.0);
+        let driven = pan.drive(0.0);
+        assert!(driven.cmple(Vec3::ONE).all());
+    }
+
+    #[test]
+    fn bookkeeping_sees_clamped_values() {
+        // The shortest way to 3.5 is backwards through 0.0, straight into
+        // the lower bound.
+        let mut angle = TransformComponent::new_angle_wrapped(0.0).with_bounds(0.0, 4.0);
+        angle.component.target = 3.5;
+
+        assert_eq!(angle.drive(0.016), 0.0);
+        assert!(!angle.component.has_moved());
+        angle.component.undo_last_drive();
+        assert_eq!(angle.component.current, 0.0);
+    }
+
+    #[test]
+    fn swapped_bounds_dont_panic() {
+        let mut zoom = TransformComponent::new_zoom(1.0_f32).with_bounds(1.0, 5.0);
+        zoom.min = 5.0;
+        zoom.max = 1.0;
+        assert!(zoom.drive(0.016).is_finite());
+    }
+}
@@ -0,0 +1,104 @@
+use core::fmt::Formatter;
+use crate::{Smoothed, TransformComponent};
+
+/// Smooths a component, but stops it once it has travelled a total
+/// distance of `budget`, for example for an energy limited camera move.
+///
+/// Travel is measured through [`Smoothed::distance`] between consecutive
+/// values, and the final step is shortened to land exactly on the budget.
+/// Once exhausted the component freezes in place until the budget is
+/// [replenished](Self::replenish).
+pub struct TravelBudget<T: Smoothed> {
+    /// The smoothed component.
+    pub component: TransformComponent<T>,
+    /// The total distance the component may travel.
+    pub budget: f32,
+    travelled: f32,
+}
+
+impl<T: Smoothed> core::fmt::Debug for TravelBudget<T> where
+    T::Attribute: core::fmt::Debug {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("TravelBudget")
+            .field("component", &self.component)
+            .field("budget", &self.budget)
+            .field("travelled", &self.travelled)
+            .finish()
+    }
+}
+
+impl<T: Smoothed> TravelBudget<T> {
+    /// Wraps `component`, allowing it to travel `budget` in total.
+    pub fn new(component: TransformComponent<T>, budget: f32) -> Self {
+        Self {
+            component,
+            budget,
+            travelled: 0.0,
+        }
+    }
+
+    /// The distance travelled so far.
+    pub fn travelled(&self) -> f32 {
+        self.travelled
+    }
+
+    /// The distance which may still be travelled.
+    pub fn remaining(&self) -> f32 {
+        (self.budget - self.travelled).max(0.0)
+    }
+
+    /// Whether the budget is used up, freezing the component.
+    pub fn is_exhausted(&self) -> bool {
+        self.remaining() <= 0.0
+    }
+
+    /// Adds `amount` to the budget, letting a frozen component move again.
+    pub fn replenish(&mut self, amount: f32) {
+        self.budget += amount;
+    }
+
+    /// Drives the component by `delta_time` seconds, as far as the
+    /// remaining budget allows.
+    pub fn drive(&mut self, delta_time: f32) -> T::Attribute {
+        let remaining = self.remaining();
+        if remaining <= 0.0 {
+            return self.component.current;
+        }
+
+        let previous = self.component.current;
+        let current = self.component.drive_step_capped(delta_time, remaining);
+        self.travelled += T::distance(previous, current).min(remaining);
+        current
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use glam::Vec3;
+    use crate::Translate;
+    use super::*;
+
+    #[test]
+    fn halts_at_budget_and_resumes() {
+        let position = TransformComponent::<Translate<Vec3>>::new(0.01, Vec3::ZERO);
+        let mut budget = TravelBudget::new(position, 2.0);
+        budget.component.target = Vec3::X * 5.0;
+
+        for _ in 0..100 {
+            budget.drive(0.016);
+        }
+        assert!(budget.is_exhausted());
+        assert!((budget.component.current.x - 2.0).abs() < 1e-4);
+
+        let frozen = budget.component.current;
+        budget.drive(0.016);
+        assert_eq!(budget.component.current, frozen);
+
+        budget.replenish(10.0);
+        for _ in 0..500 {
+            budget.drive(0.016);
+        }
+        assert!(budget.component.current.abs_diff_eq(Vec3::X * 5.0, 1e-3));
+        assert!((budget.travelled() - 5.0).abs() < 1e-3);
+    }
+}
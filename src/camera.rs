@@ -0,0 +1,363 @@
+use core::f32::consts::FRAC_PI_2;
+use glam::{Mat3, Mat4, Vec3};
+use crate::{Rotate2D, TransformComponent, Translate};
+
+/// The handedness of a coordinate system, which selects between the
+/// `_rh` and `_lh` flavours of glam's camera constructors.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Handedness {
+    /// Right handed coordinates, where the camera looks down `-Z`.
+    #[default]
+    Right,
+    /// Left handed coordinates, where the camera looks down `+Z`.
+    Left,
+}
+
+impl Handedness {
+    /// A view matrix at `eye` looking at `center`, through
+    /// [`Mat4::look_at_rh`] or [`Mat4::look_at_lh`].
+    pub fn look_at(self, eye: Vec3, center: Vec3, up: Vec3) -> Mat4 {
+        match self {
+            Handedness::Right => Mat4::look_at_rh(eye, center, up),
+            Handedness::Left => Mat4::look_at_lh(eye, center, up),
+        }
+    }
+
+    /// A view matrix at `eye` looking along `dir`, through
+    /// [`Mat4::look_to_rh`] or [`Mat4::look_to_lh`].
+    pub fn look_to(self, eye: Vec3, dir: Vec3, up: Vec3) -> Mat4 {
+        match self {
+            Handedness::Right => Mat4::look_to_rh(eye, dir, up),
+            Handedness::Left => Mat4::look_to_lh(eye, dir, up),
+        }
+    }
+
+    /// A perspective projection, through [`Mat4::perspective_rh`]
+    /// or [`Mat4::perspective_lh`].
+    pub fn perspective(self, fov_y_radians: f32, aspect_ratio: f32, z_near: f32, z_far: f32) -> Mat4 {
+        match self {
+            Handedness::Right => Mat4::perspective_rh(fov_y_radians, aspect_ratio, z_near, z_far),
+            Handedness::Left => Mat4::perspective_lh(fov_y_radians, aspect_ratio, z_near, z_far),
+        }
+    }
+}
+
+/// Combines a view and a projection into a single view-projection matrix.
+///
+/// The view is applied first and then the projection, so this is
+/// `projection * view`, matching [`compose`](crate::compose):
+/// ```
+/// # use glam::{Mat4, Vec3};
+/// # use buttery::{view_projection, FollowCamera, Handedness};
+/// let mut camera = FollowCamera::new(Vec3::new(0.0, 2.0, 5.0), Vec3::ZERO);
+/// camera.focus.target = Vec3::X;
+///
+/// let projection = Handedness::Right.perspective(1.0, 16.0 / 9.0, 0.1, 100.0);
+/// let view = camera.drive(0.016).view;
+/// let clip_from_world = view_projection(view, projection);
+/// assert_eq!(clip_from_world, projection * view);
+/// ```
+pub fn view_projection(view: Mat4, projection: Mat4) -> Mat4 {
+    crate::compose(projection, view)
+}
+
+/// A view matrix together with its inverse, the camera's world transform.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ViewMatrices {
+    /// Transforms from world space into view space.
+    pub view: Mat4,
+    /// Transforms from view space into world space.
+    pub inv_view: Mat4,
+}
+
+impl ViewMatrices {
+    /// Pairs up a rigid `view` matrix, made only of a rotation and a
+    /// translation, with its inverse.
+    ///
+    /// The inverse is found by transposing the rotation and rotating the
+    /// translation back, which is cheaper and more stable than a general
+    /// [`Mat4::inverse`]. This gives wrong results for views with scale,
+    /// shear or projection in them.
+    pub fn from_rigid(view: Mat4) -> Self {
+        let rotation = Mat3::from_mat4(view).transpose();
+        let translation = -(rotation * view.w_axis.truncate());
+        Self {
+            view,
+            inv_view: Mat4::from_cols(
+                rotation.x_axis.extend(0.0),
+                rotation.y_axis.extend(0.0),
+                rotation.z_axis.extend(0.0),
+                translation.extend(1.0),
+            ),
+        }
+    }
+}
+
+/// A camera which smoothly follows a point of focus from a smoothed position.
+///
+/// The focus is followed much more closely than the position by default,
+/// so the camera keeps its subject framed while it catches up.
+#[derive(Debug)]
+pub struct FollowCamera {
+    /// Where the camera is.
+    pub position: TransformComponent<Translate<Vec3>>,
+    /// What the camera looks at.
+    pub focus: TransformComponent<Translate<Vec3>>,
+    /// Which way is up.
+    pub up: Vec3,
+    /// The convention used to build the view matrix.
+    pub handedness: Handedness,
+}
+
+impl FollowCamera {
+    /// Creates a new right handed `FollowCamera` with `Y` up. The position
+    /// uses the retention of [`new_translate`](TransformComponent::new_translate),
+    /// and the focus a retention of `0.001`.
+    pub fn new(position: Vec3, focus: Vec3) -> Self {
+        Self {
+            position: TransformComponent::new_translate(position),
+            focus: TransformComponent::new(0.001, focus),
+            up: Vec3::Y,
+            handedness: Handedness::Right,
+        }
+    }
+
+    /// Uses `handedness` when building view matrices.
+    pub fn with_handedness(mut self, handedness: Handedness) -> Self {
+        self.handedness = handedness;
+        self
+    }
+
+    /// Drives the position and focus by `delta_time` seconds,
+    /// returning the view matrix and its inverse.
+    pub fn drive(&mut self, delta_time: f32) -> ViewMatrices {
+        let eye = self.position.drive(delta_time);
+        let center = self.focus.drive(delta_time);
+        ViewMatrices::from_rigid(self.handedness.look_at(eye, center, self.up))
+    }
+
+    /// Drives the camera like [`drive`](Self::drive), and returns the
+    /// [`view_projection`] of the view with `projection`.
+    pub fn drive_view_projection(&mut self, delta_time: f32, projection: Mat4) -> Mat4 {
+        view_projection(self.drive(delta_time).view, projection)
+    }
+}
+
+/// A camera orbiting around a smoothed point of focus, described by a
+/// smoothed yaw, pitch and distance.
+///
+/// The yaw always turns the short way around, the pitch is clamped to stay
+/// short of the poles so the camera never flips over, and the distance is
+/// clamped into `[min_distance, max_distance]`.
+/// ```
+/// # use glam::Vec3;
+/// # use buttery::Orbit;
+/// let mut orbit = Orbit::new(Vec3::ZERO, 10.0);
+///
+/// // Simulate user input:
+/// orbit.yaw.target += 0.5;
+/// orbit.pitch.target = 0.3;
+/// orbit.distance.target = 6.0;
+///
+/// // For each frame:
+/// let delta_time = 0.016;
+/// let view_matrix = orbit.drive(delta_time).view;
+/// # assert!(view_matrix.is_finite());
+/// ```
+#[derive(Debug)]
+pub struct Orbit {
+    /// The point orbited around.
+    pub focus: TransformComponent<Translate<Vec3>>,
+    /// The angle around the up axis, in radians.
+    pub yaw: TransformComponent<Rotate2D>,
+    /// The angle above the horizon, in radians.
+    pub pitch: TransformComponent<Translate<f32>>,
+    /// How far away from the focus the camera is.
+    pub distance: TransformComponent<Translate<f32>>,
+    /// The largest pitch, in either direction, the camera may reach.
+    pub max_pitch: f32,
+    /// The closest the camera may get to the focus.
+    pub min_distance: f32,
+    /// The furthest the camera may get from the focus.
+    pub max_distance: f32,
+    /// The convention used to build the view matrix.
+    pub handedness: Handedness,
+}
+
+impl Orbit {
+    /// The default [`max_pitch`](Self::max_pitch), just short of straight
+    /// up or down.
+    pub const DEFAULT_MAX_PITCH: f32 = FRAC_PI_2 - 0.01;
+
+    /// The default [`min_distance`](Self::min_distance), which keeps the
+    /// camera from sitting on the focus, where it has no direction to look.
+    pub const DEFAULT_MIN_DISTANCE: f32 = 1e-3;
+
+    /// Creates a new right handed `Orbit` around `focus` at `distance`, with
+    /// no yaw or pitch, so the camera sits on the focus' `+Z` side.
+    ///
+    /// The distance is only kept above [`DEFAULT_MIN_DISTANCE`](Self::DEFAULT_MIN_DISTANCE)
+    /// until [`min_distance`](Self::min_distance) and
+    /// [`max_distance`](Self::max_distance) are set.
+    pub fn new(focus: Vec3, distance: f32) -> Self {
+        Self {
+            focus: TransformComponent::new_translate(focus),
+            yaw: TransformComponent::new_angle_wrapped(0.0),
+            pitch: TransformComponent::new_angle(0.0),
+            distance: TransformComponent::new_zoom(distance),
+            max_pitch: Self::DEFAULT_MAX_PITCH,
+            min_distance: Self::DEFAULT_MIN_DISTANCE,
+            max_distance: f32::INFINITY,
+            handedness: Handedness::Right,
+        }
+    }
+
+    /// Uses `handedness` when building view matrices.
+    pub fn with_handedness(mut self, handedness: Handedness) -> Self {
+        self.handedness = handedness;
+        self
+    }
+
+    /// Drives every component by `delta_time` seconds, clamping the pitch
+    /// and distance, and returns the view matrix and its inverse.
+    ///
+    /// Both the targets and the current values are clamped, so values
+    /// written to either never show through out of range. Limits which
+    /// contradict each other, such as a negative `max_pitch`, don't panic;
+    /// the upper limit wins.
+    pub fn drive(&mut self, delta_time: f32) -> ViewMatrices {
+        let (max_pitch, min_distance, max_distance) = (self.max_pitch, self.min_distance, self.max_distance);
+        self.pitch.target = self.pitch.target.max(-max_pitch).min(max_pitch);
+        self.pitch.current = self.pitch.current.max(-max_pitch).min(max_pitch);
+        self.distance.target = self.distance.target.max(min_distance).min(max_distance);
+        self.distance.current = self.distance.current.max(min_distance).min(max_distance);
+
+        let focus = self.focus.drive(delta_time);
+        let yaw = self.yaw.drive(delta_time);
+        let pitch = self.pitch.drive(delta_time);
+        let distance = self.distance.drive(delta_time);
+
+        let (sin_yaw, cos_yaw) = yaw.sin_cos();
+        let (sin_pitch, cos_pitch) = pitch.sin_cos();
+        let offset = Vec3::new(cos_pitch * sin_yaw, sin_pitch, cos_pitch * cos_yaw);
+
+        ViewMatrices::from_rigid(self.handedness.look_at(focus + offset * distance, focus, Vec3::Y))
+    }
+
+    /// Drives the camera like [`drive`](Self::drive), and returns the
+    /// [`view_projection`] of the view with `projection`.
+    pub fn drive_view_projection(&mut self, delta_time: f32, projection: Mat4) -> Mat4 {
+        view_projection(self.drive(delta_time).view, projection)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn driven_view(handedness: Handedness) -> (Mat4, Vec3) {
+        let mut camera = FollowCamera::new(Vec3::new(0.0, 2.0, 5.0), Vec3::ZERO)
+            .with_handedness(handedness);
+        camera.position.target = Vec3::new(3.0, 2.0, 4.0);
+        camera.focus.target = Vec3::new(0.0, 1.0, -1.0);
+        (camera.drive(0.016).view, camera.focus.current)
+    }
+
+    #[test]
+    fn handedness_flips_side_and_forward_axes() {
+        let (right, _) = driven_view(Handedness::Right);
+        let (left, _) = driven_view(Handedness::Left);
+
+        assert!(!right.abs_diff_eq(left, 1e-3));
+        let flip = Mat4::from_scale(Vec3::new(-1.0, 1.0, -1.0));
+        assert!(left.abs_diff_eq(flip * right, 1e-5));
+    }
+
+    #[test]
+    fn focus_is_in_front() {
+        let (right, focus) = driven_view(Handedness::Right);
+        let (left, _) = driven_view(Handedness::Left);
+
+        assert!(right.transform_point3(focus).z < 0.0);
+        assert!(left.transform_point3(focus).z > 0.0);
+    }
+
+    #[test]
+    fn rigid_inverse() {
+        let mut camera = FollowCamera::new(Vec3::new(4.0, 3.0, -2.0), Vec3::new(1.0, 0.0, 1.0));
+        camera.position.target = Vec3::new(-5.0, 1.0, 2.0);
+        camera.focus.target = Vec3::new(0.0, 2.0, 0.0);
+
+        for _ in 0..10 {
+            let ViewMatrices { view, inv_view } = camera.drive(0.016);
+            assert!((view * inv_view).abs_diff_eq(Mat4::IDENTITY, 1e-5));
+            assert!(inv_view.abs_diff_eq(view.inverse(), 1e-5));
+            assert!(inv_view.w_axis.truncate().abs_diff_eq(camera.position.current, 1e-5));
+        }
+    }
+
+    #[test]
+    fn orbit_pitch_never_flips() {
+        let mut orbit = Orbit::new(Vec3::new(1.0, 0.0, 0.0), 5.0);
+        orbit.min_distance = 2.0;
+        orbit.max_distance = 8.0;
+        orbit.pitch.target = 3.0;
+        orbit.distance.target = 20.0;
+
+        for _ in 0..300 {
+            let ViewMatrices { inv_view, .. } = orbit.drive(0.016);
+            assert!(orbit.pitch.current <= Orbit::DEFAULT_MAX_PITCH);
+
+            // The camera's up axis keeps pointing upwards.
+            assert!(inv_view.y_axis.y > 0.0);
+        }
+
+        assert!((orbit.distance.current - 8.0).abs() < 1e-3);
+        let eye = orbit.drive(0.016).inv_view.w_axis.truncate();
+        assert!((eye.distance(orbit.focus.current) - orbit.distance.current).abs() < 1e-3);
+        assert!(eye.y > 7.9);
+    }
+
+    #[test]
+    fn view_projection_puts_focus_in_clip_space() {
+        let projection = Handedness::Right.perspective(1.0, 1.0, 0.1, 100.0);
+        let mut orbit = Orbit::new(Vec3::new(1.0, 2.0, 3.0), 10.0);
+        orbit.yaw.target = 1.0;
+
+        let clip_from_world = orbit.drive_view_projection(0.016, projection);
+        let focus = clip_from_world.project_point3(orbit.focus.current);
+        assert!(focus.truncate().abs_diff_eq(glam::Vec2::ZERO, 1e-4));
+        assert!(focus.z > 0.0 && focus.z < 1.0);
+    }
+
+    #[test]
+    fn orbit_stays_finite_at_zero_distance() {
+        let mut orbit = Orbit::new(Vec3::ZERO, 5.0);
+        orbit.distance.target = 0.0;
+        orbit.pitch.current = 4.0;
+
+        for _ in 0..600 {
+            assert!(orbit.drive(0.016).view.is_finite());
+        }
+        assert!(orbit.distance.current >= Orbit::DEFAULT_MIN_DISTANCE);
+        assert!(orbit.pitch.current <= Orbit::DEFAULT_MAX_PITCH);
+
+        orbit.distance.current = 0.0;
+        assert!(orbit.drive(0.016).view.is_finite());
+    }
+
+    #[test]
+    fn orbit_tolerates_contradictory_limits() {
+        let mut orbit = Orbit::new(Vec3::ZERO, 5.0);
+        orbit.max_pitch = -1.0;
+        orbit.min_distance = 10.0;
+        orbit.max_distance = 2.0;
+
+        assert!(orbit.drive(0.016).view.is_finite());
+        assert_eq!(orbit.distance.current, 2.0);
+
+        orbit.max_pitch = f32::NAN;
+        orbit.max_distance = f32::NAN;
+        assert!(orbit.drive(0.016).view.is_finite());
+    }
+}
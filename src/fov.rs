@@ -0,0 +1,92 @@
+use glam::Mat4;
+use crate::{TransformComponent, Translate};
+
+/// A smoothed vertical field of view in radians, clamped into `[min, max]`.
+///
+/// This is meant to be fed into [`Mat4::perspective_rh`] and friends, for
+/// zooming a camera through its projection instead of its scale.
+///
+/// ## Clamping
+/// The clamp is applied after interpolating, so `target` itself may lie
+/// outside of the range. In that case `current` smooths towards it as usual
+/// and then stops exactly at the boundary, rather than creeping towards it
+/// forever.
+#[derive(Debug)]
+pub struct Fov {
+    /// The smoothed angle.
+    pub angle: TransformComponent<Translate<f32>>,
+    /// The smallest allowed field of view.
+    pub min: f32,
+    /// The largest allowed field of view.
+    pub max: f32,
+}
+
+impl Fov {
+    /// Creates a new `Fov` with the retention of
+    /// [`new_zoom`](TransformComponent::new_zoom).
+    ///
+    /// `min` must not be greater than `max`, nor either be NaN. This is
+    /// asserted in debug builds; otherwise swapped bounds are put back in
+    /// order. `initial` is clamped into `[min, max]`.
+    pub fn new(initial: f32, min: f32, max: f32) -> Self {
+        debug_assert!(min <= max, "Fov bounds must satisfy min <= max, got [{min}, {max}]");
+        let (min, max) = if min > max { (max, min) } else { (min, max) };
+        Self {
+            angle: TransformComponent::new_zoom(initial.max(min).min(max)),
+            min,
+            max,
+        }
+    }
+
+    /// Drives the angle by `delta_time` seconds, clamping the result.
+    ///
+    /// Bounds written out of order don't panic; `max` wins.
+    pub fn drive(&mut self, delta_time: f32) -> f32 {
+        let angle = self.angle.drive(delta_time).max(self.min).min(self.max);
+        self.angle.current = angle;
+        angle
+    }
+
+    /// Drives the angle, and builds a right handed perspective projection
+    /// from it through [`Mat4::perspective_rh`].
+    pub fn perspective_rh(&mut self, delta_time: f32, aspect_ratio: f32, z_near: f32, z_far: f32) -> Mat4 {
+        Mat4::perspective_rh(self.drive(delta_time), aspect_ratio, z_near, z_far)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn settles_on_clamp_boundary() {
+        let mut fov = Fov::new(1.0, 0.5, 1.5);
+        fov.angle.target = 2.0;
+
+        let first = fov.drive(0.016);
+        assert!(first > 1.0 && first < 1.5);
+
+        for _ in 0..200 {
+            assert!(fov.drive(0.016) <= 1.5);
+        }
+        assert_eq!(fov.angle.current, 1.5);
+
+        fov.angle.target = 0.0;
+        for _ in 0..200 {
+            fov.drive(0.016);
+        }
+        assert_eq!(fov.angle.current, 0.5);
+        assert!(fov.perspective_rh(0.016, 16.0 / 9.0, 0.1, 100.0).is_finite());
+    }
+
+    #[test]
+    fn swapped_bounds_dont_panic() {
+        let mut fov = Fov::new(1.0, 0.5, 1.5);
+        fov.min = 1.5;
+        fov.max = 0.5;
+        assert_eq!(fov.drive(0.016), 0.5);
+
+        fov.max = f32::NAN;
+        assert!(fov.drive(0.016).is_finite());
+    }
+}
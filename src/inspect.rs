@@ -0,0 +1,75 @@
+use glam::{DQuat, DVec2, DVec3, DVec4, Quat, Vec2, Vec3, Vec3A, Vec4};
+
+/// A driven attribute value with its type erased, as collected by
+/// [`drive_verbose`](crate::Scaffold::drive_verbose) for debug tooling.
+///
+/// Attributes are converted through [`Smoothed::driven_value`](crate::Smoothed::driven_value),
+/// and those without a matching variant, such as custom composite
+/// attributes, show up as [`Other`](Self::Other).
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum DrivenValue {
+    /// A scalar, such as an angle or a zoom.
+    F32(f32),
+    /// A double precision scalar.
+    F64(f64),
+    /// A 2D vector.
+    Vec2(Vec2),
+    /// A 3D vector, including [`Vec3A`].
+    Vec3(Vec3),
+    /// A 4D vector.
+    Vec4(Vec4),
+    /// A double precision 2D vector.
+    DVec2(DVec2),
+    /// A double precision 3D vector.
+    DVec3(DVec3),
+    /// A double precision 4D vector.
+    DVec4(DVec4),
+    /// A rotation.
+    Quat(Quat),
+    /// A double precision rotation.
+    DQuat(DQuat),
+    /// A value of any other type.
+    Other,
+}
+
+macro_rules! impl_from {
+    ($($ty:ty => $variant:ident),*) => {
+        $(
+            impl From<$ty> for DrivenValue {
+                fn from(value: $ty) -> Self {
+                    Self::$variant(value.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_from!(
+    f32 => F32, f64 => F64,
+    Vec2 => Vec2, Vec3 => Vec3, Vec3A => Vec3, Vec4 => Vec4,
+    DVec2 => DVec2, DVec3 => DVec3, DVec4 => DVec4,
+    Quat => Quat, DQuat => DQuat
+);
+
+/// Values with a dedicated [`DrivenValue`] variant, which is how the
+/// built-in [`Magnitude`](crate::Magnitude) types report themselves through
+/// [`drive_verbose`](crate::Scaffold::drive_verbose).
+pub trait IntoDrivenValue {
+    /// Converts this value into its [`DrivenValue`].
+    fn into_driven_value(self) -> DrivenValue;
+}
+
+macro_rules! impl_into_driven_value {
+    ($($ty:ty),*) => {
+        $(
+            impl IntoDrivenValue for $ty {
+                #[inline(always)]
+                fn into_driven_value(self) -> DrivenValue {
+                    self.into()
+                }
+            }
+        )*
+    };
+}
+
+impl_into_driven_value!(f32, f64, Vec2, Vec3, Vec3A, Vec4, DVec2, DVec3, DVec4);
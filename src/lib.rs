@@ -1,21 +1,246 @@
 #![doc = include_str!("../readme.md")]
 
-use std::fmt::Formatter;
-use std::marker::PhantomData;
-use std::ops::{Add, Mul, Sub};
-use glam::{Mat4, Quat};
+use core::f32::consts::{PI, TAU};
+use core::fmt::Formatter;
+use core::marker::PhantomData;
+use core::ops::{Add, Mul, Sub};
+use glam::{DQuat, DVec2, DVec3, DVec4, EulerRot, IVec3, Mat3, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
+
+mod anisotropic;
+mod asymmetric;
+#[cfg(feature = "parallel")]
+mod batch;
+mod blend;
+mod bounds;
+mod budget;
+mod camera;
+mod fov;
+mod inspect;
+mod ramp;
+mod spring;
+mod timeline;
+mod transform;
+mod ui;
+
+pub use anisotropic::TranslateAnisotropic;
+pub use asymmetric::Asymmetric;
+#[cfg(feature = "parallel")]
+pub use batch::par_drive_all;
+pub use blend::{BlendMode, CrossfadeChain};
+pub use bounds::{Bounded, Clamp};
+pub use budget::TravelBudget;
+pub use camera::{view_projection, FollowCamera, Handedness, Orbit, ViewMatrices};
+pub use fov::Fov;
+pub use inspect::{DrivenValue, IntoDrivenValue};
+pub use ramp::RetentionRamp;
+pub use spring::Spring;
+pub use timeline::Timeline;
+pub use transform::{CachedTransform, RigidTransform, SmoothMatrix, Transform};
+pub use ui::{Ui2D, UiSpring};
 
 /// Describes a smoothed attribute, such as rotation or translation.
+///
+/// Composite attributes can be smoothed as one component by combining the
+/// built in interpolations, for example a pose made of a position and
+/// an orientation:
+/// ```
+/// # use glam::{Mat4, Quat, Vec3};
+/// # use buttery::{Rotate, Scaffold, Smoothed, TransformComponent, Translate};
+/// #[derive(Copy, Clone)]
+/// struct Pose {
+///     position: Vec3,
+///     orientation: Quat,
+/// }
+///
+/// struct SmoothPose;
+///
+/// impl Smoothed for SmoothPose {
+///     type Attribute = Pose;
+///     type Scalar = f32;
+///
+///     fn drive(target: Pose, current: Pose, percent: f32) -> Pose {
+///         Pose {
+///             position: Translate::drive(target.position, current.position, percent),
+///             orientation: Rotate::drive(target.orientation, current.orientation, percent),
+///         }
+///     }
+///
+///     fn distance(a: Pose, b: Pose) -> f32 {
+///         Translate::distance(a.position, b.position)
+///             .max(Rotate::distance(a.orientation, b.orientation))
+///     }
+///
+///     fn difference(from: Pose, to: Pose) -> Pose {
+///         Pose {
+///             position: Translate::difference(from.position, to.position),
+///             orientation: Rotate::difference(from.orientation, to.orientation),
+///         }
+///     }
+///
+///     fn identity() -> Pose {
+///         Pose {
+///             position: Translate::identity(),
+///             orientation: Rotate::identity(),
+///         }
+///     }
+/// }
+///
+/// let mut pose = TransformComponent::<SmoothPose>::new(0.01, Pose {
+///     position: Vec3::ZERO,
+///     orientation: Quat::IDENTITY,
+/// });
+/// pose.target.position = Vec3::X;
+///
+/// let matrix = pose.begin(|pose| Mat4::from_rotation_translation(pose.orientation, pose.position))
+///     .drive(0.016);
+/// ```
 pub trait Smoothed {
     /// The actual type that represents this attribute.
     type Attribute: Copy;
+    /// The floating point type the attribute is interpolated with, which
+    /// is also the type of the component's `retention`.
+    type Scalar: Scalar;
     /// "Drives" the current value towards the target by the percent.
     /// Expected to be some kind of linear interpolation.
-    fn drive(target: Self::Attribute, current: Self::Attribute, percent: f32) -> Self::Attribute;
+    ///
+    /// The exponential step always produces a `percent` within `[0, 1]`, but
+    /// other callers may not. Linear attributes such as [`Translate`] clamp
+    /// it into that range, guaranteeing they never overshoot the target.
+    fn drive(target: Self::Attribute, current: Self::Attribute, percent: Self::Scalar) -> Self::Attribute;
+
+    /// How far apart two values are, in whichever metric is natural for
+    /// the attribute, such as euclidean distance or the angle between them.
+    fn distance(a: Self::Attribute, b: Self::Attribute) -> f32;
+
+    /// The change which takes `from` to `to`, expressed as an attribute.
+    ///
+    /// This is subtraction for translations, and the relative rotation
+    /// `to * from.inverse()` for rotations.
+    fn difference(from: Self::Attribute, to: Self::Attribute) -> Self::Attribute;
+
+    /// The value at rest, which leaves things untransformed, such as a zero
+    /// translation, the identity rotation or a scale of one.
+    fn identity() -> Self::Attribute;
+
+    /// Re-expresses `target` so that it is the representation closest to
+    /// `current`, for attributes where several values describe the same state.
+    ///
+    /// This is applied to the stored target before each drive. By default
+    /// the target is returned untouched.
+    #[inline(always)]
+    fn align(target: Self::Attribute, _current: Self::Attribute) -> Self::Attribute {
+        target
+    }
+
+    /// Whether `value` lies in the attribute's domain, as checked by
+    /// [`validate`](TransformComponent::validate).
+    ///
+    /// By default this checks that its distance from the
+    /// [`identity`](Self::identity) is finite.
+    #[inline(always)]
+    fn is_valid(value: Self::Attribute) -> bool {
+        Self::distance(Self::identity(), value).is_finite()
+    }
+
+    /// Converts a value into a [`DrivenValue`] for inspection through
+    /// [`drive_verbose`](Scaffold::drive_verbose). By default this is
+    /// [`DrivenValue::Other`].
+    #[inline(always)]
+    fn driven_value(_value: Self::Attribute) -> DrivenValue {
+        DrivenValue::Other
+    }
+}
+
+/// A region around the target inside of which a component stops moving,
+/// to keep noisy targets from causing perpetual micro-motion.
+///
+/// Distances are measured through [`Smoothed::distance`]. A moving component
+/// comes to rest once it is closer than `enter` to its target, and only starts
+/// moving again once the target is further than `exit` away. Keeping `exit`
+/// above `enter` adds hysteresis, so a target jittering right at the boundary
+/// doesn't make the component flicker between moving and resting.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DeadZone {
+    /// The distance under which a moving component comes to rest.
+    pub enter: f32,
+    /// The distance over which a resting component starts moving again.
+    pub exit: f32,
+}
+
+impl DeadZone {
+    /// A dead zone without hysteresis, which enters and exits at `radius`.
+    pub const fn new(radius: f32) -> Self {
+        Self {
+            enter: radius,
+            exit: radius,
+        }
+    }
+
+    /// A dead zone which enters under `enter` and exits over `exit`.
+    pub const fn with_hysteresis(enter: f32, exit: f32) -> Self {
+        Self {
+            enter,
+            exit,
+        }
+    }
+}
+
+/// A misconfiguration found by [`validate`](TransformComponent::validate).
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum StabilityError {
+    /// The retention isn't within `(0, 1)`, so the component either never
+    /// moves, snaps, or diverges.
+    RetentionOutOfRange(f32),
+    /// The current value isn't finite, or isn't a valid value at all.
+    NonFiniteCurrent,
+    /// The target value isn't finite, or isn't a valid value at all.
+    NonFiniteTarget,
+}
+
+impl core::fmt::Display for StabilityError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            StabilityError::RetentionOutOfRange(retention) => write!(f, "retention {retention} is outside of (0, 1)"),
+            StabilityError::NonFiniteCurrent => write!(f, "current value is not finite"),
+            StabilityError::NonFiniteTarget => write!(f, "target value is not finite"),
+        }
+    }
 }
 
+impl std::error::Error for StabilityError {}
+
+/// The retention used by [`new_translate`](TransformComponent::new_translate).
+pub const DEFAULT_TRANSLATE_RETENTION: f32 = 0.01;
+
+/// The retention used by [`new_zoom`](TransformComponent::new_zoom).
+pub const DEFAULT_ZOOM_RETENTION: f32 = 0.03;
+
+/// The retention used by [`new_angle`](TransformComponent::new_angle).
+pub const DEFAULT_ANGLE_RETENTION: f32 = 0.04;
+
+/// The retention used by [`new_rotate`](TransformComponent::new_rotate).
+pub const DEFAULT_ROTATE_RETENTION: f32 = 0.04;
+
+/// How far a single drive must move a component, as measured by
+/// [`Smoothed::distance`], for it to count towards
+/// [`has_moved`](TransformComponent::has_moved).
+pub const MOVED_EPSILON: f32 = 1e-6;
+
+/// A threshold for [`renormalize`](TransformComponent::renormalize) which
+/// corrects drift long before it shows, while skipping the normalization for
+/// rotations which are only off by rounding.
+pub const DEFAULT_RENORM_EPSILON: f32 = 1e-4;
+
+/// The most substeps a single [`drive_fixed`](TransformComponent::drive_fixed)
+/// call runs. Time beyond that is dropped, so a long hitch can't stall the
+/// frame after it.
+pub const MAX_FIXED_SUBSTEPS: u32 = 1024;
+
+/// The smallest value [`Scale`] drives from or towards, which zero and
+/// negative scales are clamped to.
+pub const MIN_SCALE: f32 = 1e-6;
+
 /// Describes the current state of a smoothed attribute.
-#[derive(Copy, Clone)]
 pub struct TransformComponent<T: Smoothed> {
     /// How close should the current value follow the target.
     ///
@@ -23,23 +248,95 @@ pub struct TransformComponent<T: Smoothed> {
     /// however reasonable values are near `0.05` or so. Closer to
     /// `0.0` yields closer following, closer to `1.0` yields
     /// more lenient following.
-    pub retention: f32,
+    pub retention: T::Scalar,
     /// The current value.
     pub current: T::Attribute,
     /// The target value.
     pub target: T::Attribute,
+    /// An optional dead zone around `target`, inside of which `current`
+    /// stops moving. See [`DeadZone`] for details.
+    pub dead_zone: Option<DeadZone>,
+    /// An optional cap on how fast `current` moves, in units of
+    /// [`Smoothed::distance`] per second: a distance for translations, and
+    /// an angle for rotations.
+    ///
+    /// This tames the lunge after a target teleports far away, while small
+    /// movements still smooth exponentially.
+    pub max_speed: Option<f32>,
+    last_target: T::Attribute,
+    before_drive: T::Attribute,
+    primed: bool,
+    resting: bool,
+    moved: bool,
+    accumulated: f32,
+    fixed_remainder: f32,
+    integrated_error: Option<f32>,
+    settled: bool,
     _unused: PhantomData<T>,
 }
 
-impl<T: Smoothed> std::fmt::Debug for TransformComponent<T> where
-    T::Attribute: std::fmt::Debug {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+impl<T: Smoothed> Clone for TransformComponent<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Smoothed> Copy for TransformComponent<T> {}
+
+impl<T: Smoothed> From<(T::Scalar, T::Attribute)> for TransformComponent<T> {
+    /// Creates a component from a `(retention, initial)` pair, as
+    /// through [`new`](TransformComponent::new).
+    fn from((retention, initial): (T::Scalar, T::Attribute)) -> Self {
+        Self::new(retention, initial)
+    }
+}
+
+impl<T: Smoothed> core::fmt::Debug for TransformComponent<T> where
+    T::Attribute: core::fmt::Debug {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         f.debug_struct(
             "TransformComponent",
         )
             .field("retention", &self.retention)
             .field("current", &self.current)
             .field("target", &self.target)
+            .field("distance", &T::distance(self.current, self.target))
+            .finish()
+    }
+}
+
+/// Formats as `current -> target (retention r, distance d)`.
+impl<T: Smoothed> core::fmt::Display for TransformComponent<T> where
+    T::Attribute: core::fmt::Display {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{} -> {} (retention {}, distance {})",
+            self.current,
+            self.target,
+            self.retention,
+            T::distance(self.current, self.target),
+        )
+    }
+}
+
+/// The complete state of a [`TransformComponent`], as captured by
+/// [`snapshot`](TransformComponent::snapshot).
+pub struct TransformSnapshot<T: Smoothed>(TransformComponent<T>);
+
+impl<T: Smoothed> Clone for TransformSnapshot<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Smoothed> Copy for TransformSnapshot<T> {}
+
+impl<T: Smoothed> core::fmt::Debug for TransformSnapshot<T> where
+    T::Attribute: core::fmt::Debug {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("TransformSnapshot")
+            .field(&self.0)
             .finish()
     }
 }
@@ -50,28 +347,617 @@ impl<T: Smoothed> TransformComponent<T> {
     ///
     /// This usually isn't called manually, and instead the [`begin`](Self::begin) interface is preferred.
     pub fn drive(&mut self, delta_time: f32) -> T::Attribute {
-        let percent = 1.0 - self.retention.powf(delta_time);
-        let new_current = T::drive(self.target, self.current, percent);
-        self.current = new_current;
-        new_current
+        self.advance(T::Scalar::from_f32(delta_time), None)
+    }
+
+    /// Drives the attribute like [`drive`](Self::drive), but takes
+    /// `delta_time` in the attribute's own [`Scalar`] type.
+    ///
+    /// This lets `f64` attributes, such as [`Translate<DVec3>`](Translate)
+    /// or [`Rotate64`], advance by an `f64` delta time end to end.
+    pub fn drive_scalar(&mut self, delta_time: T::Scalar) -> T::Attribute {
+        self.advance(delta_time, None)
+    }
+
+    /// Drives the attribute like [`drive`](Self::drive), but maps
+    /// `(retention, delta_time)` to the percent to move through `curve`
+    /// instead of [`exponential_percent`].
+    ///
+    /// Only the time to percent mapping changes; the interpolation itself
+    /// is still [`Smoothed::drive`]. For example, a fixed linear rate:
+    /// ```
+    /// # use glam::Vec3;
+    /// # use buttery::TransformComponent;
+    /// let mut position = TransformComponent::new_translate(Vec3::ZERO);
+    /// position.target = Vec3::X;
+    ///
+    /// let current = position.drive_with(0.1, |_retention, delta_time| (delta_time * 2.0).min(1.0));
+    /// assert!((current.x - 0.2).abs() < 1e-6);
+    /// ```
+    pub fn drive_with(&mut self, delta_time: f32, curve: impl FnOnce(T::Scalar, T::Scalar) -> T::Scalar) -> T::Attribute {
+        self.advance_with(T::Scalar::from_f32(delta_time), None, curve, |value| value)
+    }
+
+    /// Sets `target` and drives the attribute towards it, which makes
+    /// following a value that lives elsewhere a one-liner.
+    ///
+    /// The supplied target is stored, so everything that inspects `target`
+    /// afterwards sees it.
+    pub fn drive_toward(&mut self, target: T::Attribute, delta_time: f32) -> T::Attribute {
+        self.target = target;
+        self.drive(delta_time)
+    }
+
+    /// Returns the value [`drive`](Self::drive) would produce after
+    /// `delta_time` seconds, without modifying the component.
+    ///
+    /// This runs the very same step on a copy, so the two never disagree.
+    /// It is useful to render a predicted pose, and only commit to it
+    /// through `drive` later.
+    pub fn peek(&self, delta_time: f32) -> T::Attribute {
+        let mut copy = *self;
+        copy.drive(delta_time)
+    }
+
+    /// Interpolates from `current` towards `target` by `t`, without
+    /// modifying the component. A `t` of `0.0` yields `current`, and `1.0`
+    /// yields `target`.
+    pub fn at_fraction(&self, t: f32) -> T::Attribute {
+        T::drive(T::align(self.target, self.current), self.current, T::Scalar::from_f32(t))
+    }
+
+    /// Drives the attribute like [`drive`](Self::drive), but never moves
+    /// `current` further than `max_step` in a single call, no matter how
+    /// large `delta_time` is.
+    ///
+    /// This bounds the visual pop after a hitch. The step is measured through
+    /// [`Smoothed::distance`], so it is a distance for translations and an
+    /// angle for rotations. Unlike a speed limit it doesn't scale with
+    /// `delta_time`.
+    pub fn drive_step_capped(&mut self, delta_time: f32, max_step: f32) -> T::Attribute {
+        self.advance(T::Scalar::from_f32(delta_time), Some(max_step))
+    }
+
+    /// The shared implementation of the `drive` family, optionally
+    /// limiting how far `current` may move.
+    fn advance(&mut self, delta_time: T::Scalar, max_step: Option<f32>) -> T::Attribute {
+        self.advance_with(delta_time, max_step, exponential_percent, |value| value)
+    }
+
+    /// Drives the attribute like [`drive`](Self::drive), passing every new
+    /// `current` through `bound` before any of the bookkeeping sees it.
+    pub(crate) fn drive_bounded(&mut self, delta_time: f32, bound: impl Fn(T::Attribute) -> T::Attribute) -> T::Attribute {
+        self.advance_with(T::Scalar::from_f32(delta_time), None, exponential_percent, bound)
+    }
+
+    fn advance_with(
+        &mut self,
+        delta_time: T::Scalar,
+        max_step: Option<f32>,
+        curve: impl FnOnce(T::Scalar, T::Scalar) -> T::Scalar,
+        bound: impl Fn(T::Attribute) -> T::Attribute,
+    ) -> T::Attribute {
+        self.target = T::align(self.target, self.current);
+        self.last_target = self.target;
+        let previous = self.current;
+        self.before_drive = previous;
+
+        if self.primed {
+            self.primed = false;
+            self.current = bound(self.target);
+        } else {
+            if !self.update_resting() {
+                let percent = curve(self.retention, delta_time);
+                let next = T::drive(self.target, self.current, percent);
+                let max_step = match (max_step, self.max_speed) {
+                    (Some(max_step), Some(max_speed)) => Some(max_step.min(max_speed * delta_time.to_f32())),
+                    (max_step, None) => max_step,
+                    (None, Some(max_speed)) => Some(max_speed * delta_time.to_f32()),
+                };
+                self.current = bound(match max_step {
+                    Some(max_step) => Self::cap_step(self.current, next, max_step),
+                    None => next,
+                });
+            }
+
+            if let Some(error) = &mut self.integrated_error {
+                *error += T::distance(self.current, self.target) * delta_time.to_f32();
+            }
+        }
+
+        if !self.moved && T::distance(previous, self.current) > MOVED_EPSILON {
+            self.moved = true;
+        }
+
+        self.current
+    }
+
+    /// Moves from `from` towards `to`, but no further than `max_step`.
+    ///
+    /// The interpolation itself is used to shorten the step, so this works
+    /// for every attribute whose interpolation is uniform in its distance.
+    fn cap_step(from: T::Attribute, to: T::Attribute, max_step: f32) -> T::Attribute {
+        let distance = T::distance(from, to);
+        if distance > max_step {
+            T::drive(to, from, T::Scalar::from_f32(max_step / distance))
+        } else {
+            to
+        }
+    }
+
+    /// Updates whether the component rests inside of its dead zone,
+    /// returning whether it does.
+    fn update_resting(&mut self) -> bool {
+        if let Some(dead_zone) = self.dead_zone {
+            let distance = T::distance(self.current, self.target);
+            self.resting = if self.resting {
+                distance <= dead_zone.exit
+            } else {
+                distance < dead_zone.enter
+            };
+        } else {
+            self.resting = false;
+        }
+        self.resting
+    }
+
+    /// Accumulates `delta_time`, and only drives the attribute once at least
+    /// `min_interval` seconds have built up, returning `None` otherwise.
+    ///
+    /// This lets low priority components update at a reduced rate. Since
+    /// the smoothing is exponential, one step by the accumulated time lands
+    /// exactly where the individual steps would have.
+    pub fn drive_accumulated(&mut self, delta_time: f32, min_interval: f32) -> Option<T::Attribute> {
+        self.accumulated += delta_time;
+        if self.accumulated < min_interval {
+            return None;
+        }
+
+        let elapsed = core::mem::take(&mut self.accumulated);
+        Some(self.drive(elapsed))
+    }
+
+    /// Drives the attribute in fixed substeps of `step` seconds, carrying
+    /// whatever is left of `delta_time` over to the next call.
+    ///
+    /// Every substep is the very same computation, so the sequence of values
+    /// `current` runs through is bit-identical no matter how the real frame
+    /// times split up; only how many substeps land in each frame differs.
+    /// This keeps replays deterministic across frame rates. If `step` isn't
+    /// positive, this is the same as [`drive`](Self::drive).
+    ///
+    /// A single call runs at most [`MAX_FIXED_SUBSTEPS`], and drops any
+    /// time beyond them.
+    ///
+    /// [`undo_last_drive`](Self::undo_last_drive) reverts every substep of
+    /// the most recent call at once.
+    pub fn drive_fixed(&mut self, delta_time: f32, step: f32) -> T::Attribute {
+        if step.is_nan() || step <= 0.0 {
+            return self.drive(delta_time);
+        }
+
+        // Undo should revert the whole call, not just its last substep.
+        let before = self.current;
+        for _ in 0..fixed_steps(&mut self.fixed_remainder, delta_time, step) {
+            self.drive(step);
+        }
+        self.before_drive = before;
+        self.current
+    }
+
+    /// Picks a `retention` between `loose` and `tight` from the distance
+    /// to the target, and then drives the attribute like [`drive`](Self::drive).
+    ///
+    /// An error of `error_scale` or more uses `tight`, and no error uses
+    /// `loose`. In between the retention is interpolated geometrically,
+    /// since equal ratios of retention feel like equal changes in speed.
+    /// The picked retention is stored in `retention`.
+    pub fn drive_adaptive(&mut self, delta_time: f32, tight: T::Scalar, loose: T::Scalar, error_scale: f32) -> T::Attribute {
+        let error = T::distance(self.current, T::align(self.target, self.current));
+        let t = if error_scale > 0.0 { (error / error_scale).clamp(0.0, 1.0) } else { 1.0 };
+        let t = T::Scalar::from_f32(t);
+        self.retention = loose.powf(T::Scalar::ONE - t) * tight.powf(t);
+        self.drive(delta_time)
+    }
+
+    /// Sets `retention` to `map(input_speed)`, and then drives the
+    /// attribute like [`drive`](Self::drive).
+    ///
+    /// Unlike [`drive_adaptive`](Self::drive_adaptive) this is keyed on how
+    /// fast the user's input is moving, such as the mouse speed of a
+    /// mouse-look camera, so that flicks feel responsive and slow aiming
+    /// feels smooth. The mapped retention is stored in `retention`.
+    pub fn drive_input_adaptive(&mut self, input_speed: f32, delta_time: f32, map: impl Fn(f32) -> T::Scalar) -> T::Attribute {
+        self.retention = map(input_speed);
+        self.drive(delta_time)
+    }
+
+    /// Drives the attribute like [`drive`](Self::drive), and also returns
+    /// whether it settled within `epsilon` of the target during this call.
+    ///
+    /// This reports `true` exactly once per settle, which makes it suitable
+    /// for triggering events when something reaches its mark. It re-arms once
+    /// the distance exceeds `epsilon` again, such as after a new target. A
+    /// component created at rest starts out settled.
+    pub fn drive_settling(&mut self, delta_time: f32, epsilon: f32) -> (T::Attribute, bool) {
+        let current = self.drive(delta_time);
+        let settled = T::distance(current, self.target) <= epsilon;
+        let just_settled = settled && !self.settled;
+        self.settled = settled;
+        (current, just_settled)
+    }
+
+    /// Drives the attribute across `total` seconds in fixed steps of `step`
+    /// seconds, yielding `current` after each one.
+    ///
+    /// This is useful for pre-baking a keyframe track. The final step is
+    /// shortened so exactly `total` seconds are covered, and a `step` that
+    /// isn't positive yields nothing. The component is only driven as the
+    /// iterator is consumed.
+    pub fn drive_frames(&mut self, total: f32, step: f32) -> impl Iterator<Item = T::Attribute> + '_ {
+        // Count the frames up front, so that rounding error can't add a
+        // sliver of a frame at the end. A `total` within rounding error of a
+        // whole number of steps ends on a full step.
+        let (frames, last) = if step > 0.0 && total > 0.0 && total.is_finite() {
+            let ratio = total / step;
+            let whole = ratio.round();
+            if whole >= 1.0 && (whole * step - total).abs() <= step * f32::EPSILON * whole {
+                (whole as u32, step)
+            } else {
+                let frames = ratio.ceil().max(1.0) as u32;
+                (frames, total - (frames - 1) as f32 * step)
+            }
+        } else {
+            (0, 0.0)
+        };
+
+        (0..frames).map(move |frame| {
+            let delta_time = if frame + 1 == frames { last } else { step };
+            self.drive(delta_time)
+        })
+    }
+
+    /// Drives the attribute like [`drive`](Self::drive), but returns the
+    /// change applied this frame instead of the new value.
+    ///
+    /// The change is measured through [`Smoothed::difference`], so for
+    /// translations adding it to the previous `current` yields the new one,
+    /// and for rotations multiplying it onto the previous `current` does.
+    pub fn drive_delta(&mut self, delta_time: f32) -> T::Attribute {
+        let previous = self.current;
+        let new_current = self.drive(delta_time);
+        T::difference(previous, new_current)
+    }
+
+    /// Drives the attribute like [`drive`](Self::drive), and writes the
+    /// matrix `f` builds from it into `out`.
+    ///
+    /// This is handy in ECS-style systems where the result belongs in
+    /// a sibling component:
+    /// ```
+    /// # use glam::{Mat4, Vec3};
+    /// # use buttery::TransformComponent;
+    /// let mut position = TransformComponent::new_translate(Vec3::ZERO);
+    /// let mut world = Mat4::IDENTITY;
+    ///
+    /// position.target = Vec3::X;
+    /// position.drive_into(0.016, &mut world, Mat4::from_translation);
+    /// assert_eq!(world, Mat4::from_translation(position.current));
+    /// ```
+    pub fn drive_into<O, F: FnOnce(T::Attribute) -> O>(&mut self, delta_time: f32, out: &mut O, f: F) {
+        *out = f(self.drive(delta_time));
+    }
+
+    /// Restores `current` to the value it held before the most recent
+    /// drive, for single step rollback.
+    ///
+    /// Only one value is kept, so undoing twice in a row has the same
+    /// effect as undoing once. Everything besides `current` is left as is.
+    pub fn undo_last_drive(&mut self) {
+        self.current = self.before_drive;
     }
 
     /// Forcibly sets the target and current value to something.
     ///
-    /// This snaps the values to the requested target.
+    /// This snaps the values to the requested target. To only change one of
+    /// them, see [`set_target`](Self::set_target) and
+    /// [`snap_to_target`](Self::snap_to_target).
     pub fn hard_set(&mut self, target: T::Attribute) {
         self.target = target;
         self.current = target;
     }
 
+    /// Sets `target`, leaving `current` to smooth towards it as usual.
+    pub fn set_target(&mut self, target: T::Attribute) {
+        self.target = target;
+    }
+
+    /// Snaps `current` onto the existing `target`, skipping any catch up,
+    /// for example after a loading pause.
+    pub fn snap_to_target(&mut self) {
+        self.current = self.target;
+    }
+
+    /// Moves only `current`, leaving `target` alone.
+    ///
+    /// Unlike [`hard_set`](Self::hard_set) this doesn't snap to the target;
+    /// it is meant for blending in a correction, such as an authoritative
+    /// pose from a server, which then smooths out over the following drives.
+    pub fn nudge_current(&mut self, new_current: T::Attribute) {
+        self.current = new_current;
+    }
+
+    /// Sets `target`, and picks the `retention` with which `current` will
+    /// have come within `epsilon` of it after `arrive_in` seconds.
+    ///
+    /// Exponential smoothing shrinks the distance to the target by a factor
+    /// of `retention` every second, so this solves `distance * retention^arrive_in = epsilon`
+    /// for `retention`. If `current` is already within `epsilon` the retention
+    /// is left alone. If `arrive_in` or `epsilon` isn't positive, or is NaN,
+    /// the component snaps instead.
+    pub fn retarget_by(&mut self, new_target: T::Attribute, arrive_in: f32, epsilon: f32) {
+        self.target = new_target;
+        let distance = T::distance(self.current, new_target);
+        if distance <= epsilon {
+            return;
+        }
+
+        if arrive_in > 0.0 && epsilon > 0.0 {
+            self.retention = T::Scalar::from_f32(epsilon / distance).powf(T::Scalar::from_f32(arrive_in.recip()));
+        } else {
+            self.hard_set(new_target);
+        }
+    }
+
+    /// Snaps both `current` and `target` to the attribute's
+    /// [`identity`](Smoothed::identity).
+    pub fn reset_to_identity(&mut self) {
+        self.hard_set(T::identity());
+    }
+
+    /// Primes the component so that the next call to [`drive`](Self::drive)
+    /// snaps `current` to `target` instead of smoothing towards it.
+    ///
+    /// This is useful when the real target is only known after construction,
+    /// and avoids an opening sweep from the initial value. Every drive after
+    /// that smooths as usual.
+    pub fn prime(&mut self) {
+        self.primed = true;
+    }
+
+    /// Whether `current` has come within `epsilon` of `target`, as
+    /// measured by [`Smoothed::distance`].
+    ///
+    /// Idle components can be skipped while they are settled, for example
+    /// to avoid recomputing matrices for a stationary camera.
+    pub fn is_settled(&self, epsilon: f32) -> bool {
+        T::distance(self.current, T::align(self.target, self.current)) <= epsilon
+    }
+
+    /// The distance left between `current` and `target`, as measured by
+    /// [`Smoothed::distance`].
+    pub fn remaining(&self) -> f32 {
+        T::distance(self.current, T::align(self.target, self.current))
+    }
+
+    /// How many seconds of driving it takes until `current` comes within
+    /// `epsilon` of `target`, if the target stays put.
+    ///
+    /// Exponential smoothing shrinks the distance by a factor of `retention`
+    /// every second, so this solves `remaining * retention^t = epsilon` for
+    /// `t` directly. It is `0.0` if already within `epsilon`, and infinite if
+    /// `retention` is `1.0` or more and so never converges. A `retention` of
+    /// `0.0` snaps onto the target in a drive of any length, so it is `0.0`
+    /// then too, as it is for invalid negative retentions. The
+    /// [`dead_zone`](Self::dead_zone) and [`max_speed`](Self::max_speed) are
+    /// not taken into account.
+    pub fn time_to_settle(&self, epsilon: f32) -> f32 {
+        let remaining = self.remaining();
+        if remaining <= epsilon {
+            return 0.0;
+        }
+
+        let retention = self.retention.to_f32();
+        if retention >= 1.0 {
+            return f32::INFINITY;
+        }
+        if retention <= 0.0 {
+            return 0.0;
+        }
+        (epsilon / remaining).ln() / retention.ln()
+    }
+
+    /// Returns whether `target` was modified since the last call to
+    /// [`drive`](Self::drive).
+    ///
+    /// Drives store `target` [aligned](Smoothed::align) to `current`, so
+    /// `target` is aligned the same way before comparing. Writing the same
+    /// value back to `target` doesn't count as a change, even when it is
+    /// only stored in another form, such as a far hemisphere quaternion.
+    /// Beyond that the comparison is exact.
+    pub fn target_changed(&self) -> bool
+    where T::Attribute: PartialEq {
+        T::align(self.target, self.current) != self.last_target
+    }
+
+    /// Whether any drive has moved `current` by more than [`MOVED_EPSILON`]
+    /// since this component was created.
+    ///
+    /// Unlike [`target_changed`](Self::target_changed) this never resets,
+    /// which makes it useful to skip work until the first meaningful update.
+    pub fn has_moved(&self) -> bool {
+        self.moved
+    }
+
+    /// Starts accumulating the [`integrated_error`](Self::integrated_error)
+    /// of this component on every drive.
+    ///
+    /// Tracking is opt-in since it costs a distance computation per drive.
+    pub fn track_error(&mut self) {
+        self.integrated_error.get_or_insert(0.0);
+    }
+
+    /// The total lag accumulated since tracking began, namely the sum of
+    /// `distance(current, target) * delta_time` over every drive.
+    ///
+    /// This gives a single number to compare retention settings by, and
+    /// is `0.0` unless [`track_error`](Self::track_error) was called.
+    pub fn integrated_error(&self) -> f32 {
+        self.integrated_error.unwrap_or(0.0)
+    }
+
+    /// Resets the [`integrated_error`](Self::integrated_error) to zero,
+    /// leaving tracking enabled if it was.
+    pub fn reset_integrated_error(&mut self) {
+        if let Some(error) = &mut self.integrated_error {
+            *error = 0.0;
+        }
+    }
+
     /// Creates a new `TransformComponent` with the requested
     /// retention and initial value. See the field documentation
     /// for details on how `retention` works.
-    pub fn new(retention: f32, initial: T::Attribute) -> Self {
+    ///
+    /// This is a `const fn`, so components may be declared as
+    /// `const`s or `static`s, for example in tables of presets.
+    pub const fn new(retention: T::Scalar, initial: T::Attribute) -> Self {
         Self {
             retention,
             current: initial,
             target: initial,
+            dead_zone: None,
+            max_speed: None,
+            last_target: initial,
+            before_drive: initial,
+            primed: false,
+            resting: false,
+            moved: false,
+            accumulated: 0.0,
+            fixed_remainder: 0.0,
+            integrated_error: None,
+            settled: true,
+            _unused: PhantomData,
+        }
+    }
+
+    /// Checks this component for misconfiguration which would make it
+    /// silently misbehave, such as a retention outside of `(0, 1)` or a
+    /// non-finite value.
+    ///
+    /// This is meant to be called during development, for example through
+    /// `debug_assert!(component.validate().is_ok())`. Values are checked by
+    /// measuring their [`distance`](Smoothed::distance) from the
+    /// [`identity`](Smoothed::identity), which also catches values outside of
+    /// an attribute's domain, such as a zero [`Scale`].
+    pub fn validate(&self) -> Result<(), StabilityError> {
+        if !(self.retention > T::Scalar::ZERO && self.retention < T::Scalar::ONE) {
+            return Err(StabilityError::RetentionOutOfRange(self.retention.to_f32()));
+        }
+        if !T::is_valid(self.current) {
+            return Err(StabilityError::NonFiniteCurrent);
+        }
+        if !T::is_valid(self.target) {
+            return Err(StabilityError::NonFiniteTarget);
+        }
+        Ok(())
+    }
+
+    /// Captures the complete state of this component, including internal
+    /// bookkeeping such as the dead zone state and accumulated time.
+    ///
+    /// This is a plain copy without any allocation, suitable for rollback.
+    pub fn snapshot(&self) -> TransformSnapshot<T> {
+        TransformSnapshot(*self)
+    }
+
+    /// Restores the complete state captured by [`snapshot`](Self::snapshot).
+    pub fn restore(&mut self, snapshot: TransformSnapshot<T>) {
+        *self = snapshot.0;
+    }
+
+    /// Creates a new `TransformComponent` which behaves like a one-pole
+    /// low-pass filter with a cutoff frequency of `cutoff_hz`.
+    ///
+    /// ## Derivation
+    /// A one-pole low-pass filter has the time constant `τ = 1 / (2π·fc)`, and
+    /// its step response keeps `exp(-t / τ)` of the remaining distance after `t`
+    /// seconds. Exponential smoothing keeps `retention^t`, so the two agree
+    /// when `retention = exp(-2π·fc)`. Since `retention` is measured per second
+    /// rather than per frame, this holds at any frame rate.
+    pub fn from_cutoff(cutoff_hz: f32, initial: T::Attribute) -> Self {
+        Self::new(T::Scalar::from_f32((-TAU * cutoff_hz).exp()), initial)
+    }
+
+    /// The cutoff frequency in hertz of the one-pole low-pass filter this
+    /// component behaves like. See [`from_cutoff`](Self::from_cutoff).
+    pub fn cutoff_hz(&self) -> f32 {
+        -self.retention.to_f32().ln() / TAU
+    }
+
+    /// Creates a new `TransformComponent` which covers half of the
+    /// remaining distance every `half_life` seconds.
+    ///
+    /// This is often easier to reason about than `retention`, which is
+    /// found as `0.5^(1 / half_life)`. `half_life` must be positive, which
+    /// is checked in debug builds.
+    pub fn from_half_life(half_life: f32, initial: T::Attribute) -> Self {
+        debug_assert!(half_life > 0.0, "half life must be positive, got {half_life}");
+        Self::new(T::Scalar::from_f32(0.5f32.powf(half_life.recip())), initial)
+    }
+
+    /// Creates a new `TransformComponent` with the time constant `tau`
+    /// in seconds, after which `1 / e` of the remaining distance is left.
+    ///
+    /// The retention is found as `exp(-1 / tau)`. `tau` must be positive,
+    /// which is checked in debug builds.
+    pub fn from_time_constant(tau: f32, initial: T::Attribute) -> Self {
+        debug_assert!(tau > 0.0, "time constant must be positive, got {tau}");
+        Self::new(T::Scalar::from_f32((-tau.recip()).exp()), initial)
+    }
+
+    /// How many seconds it takes to cover half of the remaining distance.
+    /// See [`from_half_life`](Self::from_half_life).
+    ///
+    /// The retention must lie within `(0, 1)`, which is checked in debug
+    /// builds; see [`validate`](Self::validate).
+    pub fn half_life(&self) -> f32 {
+        let retention = self.retention.to_f32();
+        debug_assert!(retention > 0.0 && retention < 1.0, "retention must lie within (0, 1), got {retention}");
+        0.5f32.ln() / retention.ln()
+    }
+
+    /// Creates a new `TransformComponent` with `current` and `target`
+    /// set independently, unlike [`new`](Self::new) which sets both to
+    /// the same value.
+    ///
+    /// This is useful for building known states in tests, or for restoring
+    /// state which was saved mid-flight.
+    pub const fn with_state(retention: T::Scalar, current: T::Attribute, target: T::Attribute) -> Self {
+        let mut component = Self::new(retention, current);
+        component.target = target;
+        component.last_target = target;
+        component.settled = false;
+        component
+    }
+
+    /// Reuses this component's state with a different interpolation
+    /// over the same attribute type.
+    pub(crate) fn reinterpret<U: Smoothed<Attribute = T::Attribute, Scalar = T::Scalar>>(self) -> TransformComponent<U> {
+        TransformComponent {
+            retention: self.retention,
+            current: self.current,
+            target: self.target,
+            dead_zone: self.dead_zone,
+            max_speed: self.max_speed,
+            last_target: self.last_target,
+            before_drive: self.before_drive,
+            primed: self.primed,
+            resting: self.resting,
+            moved: self.moved,
+            accumulated: self.accumulated,
+            fixed_remainder: self.fixed_remainder,
+            integrated_error: self.integrated_error,
+            settled: self.settled,
             _unused: PhantomData,
         }
     }
@@ -100,137 +986,2658 @@ impl<T: Smoothed> TransformComponent<T> {
     /// let translation = Vec3::new(1.0, 3.0, 5.0);
     /// let transform_matrix = Mat4::from_translation(translation) * Mat4::from_scale(Vec3::ONE * zoom);
     /// ```
-    pub fn begin<F: FnOnce(T::Attribute) -> Mat4>(&mut self, f: F) -> First<T, F> {
-        First {
-            component: self,
-            f,
-        }
+    ///
+    /// The closure may return any type which composes through
+    /// multiplication, such as [`Affine3A`](glam::Affine3A), which is cheaper
+    /// to multiply and invert than [`Mat4`]. Every stage queued afterwards
+    /// must return the same type.
+    pub fn begin<O, F: FnOnce(T::Attribute) -> O>(&mut self, f: F) -> First<'_, Self, F, O> {
+        First::new(self, f)
     }
 }
 
 impl<T> TransformComponent<Translate<T>>
-where T: Add<T, Output=T> + Mul<f32, Output=T> + Sub<T, Output=T> + Copy
+where T: Add<T, Output=T> + Mul<f32, Output=T> + Sub<T, Output=T> + Magnitude<Scalar = f32> + Default + Copy
 {
-    /// Creates a new `TransformComponent` with a retention of `0.01`.
-    pub fn new_translate(initial_state: T) -> Self {
-        Self::new(0.01, initial_state)
+    /// Creates a new `TransformComponent` with a retention of
+    /// [`DEFAULT_TRANSLATE_RETENTION`].
+    pub const fn new_translate(initial_state: T) -> Self {
+        Self::new(DEFAULT_TRANSLATE_RETENTION, initial_state)
     }
 
-    /// Creates a new `TransformComponent` with a retention of `0.03`.
-    pub fn new_zoom(initial_state: T) -> Self {
-        Self::new(0.03, initial_state)
+    /// Creates a new `TransformComponent` with a retention of
+    /// [`DEFAULT_ZOOM_RETENTION`].
+    ///
+    /// This interpolates linearly, which is kept for compatibility. Zooms
+    /// usually feel better interpolated geometrically through
+    /// [`new_scale`](TransformComponent::<Scale>::new_scale).
+    pub const fn new_zoom(initial_state: T) -> Self {
+        Self::new(DEFAULT_ZOOM_RETENTION, initial_state)
     }
 
-    /// Creates a new `TransformComponent` with a retention of `0.04`.
-    pub fn new_angle(initial_state: T) -> Self {
-        Self::new(0.04, initial_state)
+    /// Creates a new `TransformComponent` with a retention of
+    /// [`DEFAULT_ANGLE_RETENTION`].
+    ///
+    /// The angle interpolates linearly and accumulates without bound, so
+    /// going from `0.1` to `6.2` sweeps almost all the way around. Use
+    /// [`new_angle_wrapped`](TransformComponent::<Angle>::new_angle_wrapped)
+    /// for headings which should take the shortest way around instead.
+    pub const fn new_angle(initial_state: T) -> Self {
+        Self::new(DEFAULT_ANGLE_RETENTION, initial_state)
     }
 }
 
-impl TransformComponent<Rotate> {
-    /// Creates a new `TransformComponent<Rotate>` with a retention of `0.04`.
-    pub fn new_rotate(initial_state: Quat) -> Self {
-        Self::new(0.04, initial_state)
+impl TransformComponent<Translate<Vec3>> {
+    /// Creates a new `TransformComponent` positioned at grid cell `cell`,
+    /// where each cell is `cell_size` world units wide. Uses the same
+    /// retention as [`new_translate`](Self::new_translate).
+    pub fn new_grid(cell: IVec3, cell_size: f32) -> Self {
+        Self::new_translate(cell.as_vec3() * cell_size)
     }
-}
 
-/// Represents anything whose interpolation looks like `(1 - t) * a + t * (b - a)`.
-pub struct Translate<T>(PhantomData<T>);
+    /// Sets the target to the world position of grid cell `cell`, where
+    /// each cell is `cell_size` world units wide.
+    ///
+    /// The smoothing itself still happens in world space, so the displayed
+    /// position glides between cells.
+    pub fn set_grid_target(&mut self, cell: IVec3, cell_size: f32) {
+        self.target = cell.as_vec3() * cell_size;
+    }
 
-impl<T> Smoothed for Translate<T>
-where T: Add<T, Output = T> + Mul<f32, Output = T> + Sub<T, Output = T> + Copy {
-    type Attribute = T;
-    fn drive(target: T, current: T, percent: f32) -> T {
-        current + (target - current) * percent
+    /// Drives the component like [`drive`](Self::drive), but snaps
+    /// `current` onto `target` once it is within half of `pixel_size`.
+    ///
+    /// This avoids the long sub-pixel tail of exponential smoothing when
+    /// the display position is meant to come to rest exactly on a cell.
+    pub fn drive_snapped(&mut self, delta_time: f32, pixel_size: f32) -> Vec3 {
+        let new_current = self.drive(delta_time);
+        if new_current.distance(self.target) < pixel_size * 0.5 {
+            self.current = self.target;
+        }
+        self.current
     }
 }
 
-/// Represents quaternion interpolation through [`slerp`](Quat::slerp).
-pub struct Rotate;
+impl TransformComponent<Angle> {
+    /// Creates a new `TransformComponent` for an angle in radians which
+    /// takes the shortest way around the circle, with a retention of
+    /// [`DEFAULT_ANGLE_RETENTION`].
+    ///
+    /// The angle is kept wrapped into `[-π, π)`, unlike
+    /// [`new_angle`](TransformComponent::new_angle) which accumulates.
+    pub const fn new_angle_wrapped(initial_state: f32) -> Self {
+        Self::new(DEFAULT_ANGLE_RETENTION, initial_state)
+    }
+}
 
-impl Smoothed for Rotate {
-    type Attribute = Quat;
-    fn drive(target: Quat, current: Quat, percent: f32) -> Quat {
-        current.slerp(target, percent).normalize()
+impl<T> TransformComponent<Scale<T>>
+where Scale<T>: Smoothed<Attribute = T, Scalar = f32>
+{
+    /// Creates a new `TransformComponent` which interpolates geometrically
+    /// through [`Scale`], with a retention of [`DEFAULT_ZOOM_RETENTION`].
+    pub const fn new_scale(initial_state: T) -> Self {
+        Self::new(DEFAULT_ZOOM_RETENTION, initial_state)
     }
 }
 
-/// Implementation detail. Yielded from [`.begin`](TransformComponent::begin).
-pub struct First<'a, T: Smoothed, F: FnOnce(T::Attribute) -> Mat4> {
-    component: &'a mut TransformComponent<T>,
-    f: F,
+impl TransformComponent<Translate<Vec2>> {
+    /// Drives the component like [`drive`](Self::drive), but rounds the
+    /// returned value to the nearest pixel, `pixels_per_unit` to a unit.
+    ///
+    /// Only the output is rounded, while `current` stays unsnapped, so
+    /// motion remains smooth over time instead of stalling on the grid.
+    /// This keeps pixel art from shimmering between pixels.
+    pub fn drive_pixel_snapped(&mut self, delta_time: f32, pixels_per_unit: f32) -> Vec2 {
+        (self.drive(delta_time) * pixels_per_unit).round() / pixels_per_unit
+    }
 }
 
-/// Result of calling [`.and_then`](Scaffold::and_then).
-pub struct Composition<'a, T: Smoothed, F: FnOnce(T::Attribute) -> Mat4, I: Scaffold + 'a> {
-    component: &'a mut TransformComponent<T>,
-    f: F,
-    inner: I,
+impl TransformComponent<Rotate64> {
+    /// Creates a new `TransformComponent<Rotate64>` with a retention of
+    /// [`DEFAULT_ROTATE_RETENTION`].
+    pub const fn new_rotate64(initial_state: DQuat) -> Self {
+        Self::new(DEFAULT_ROTATE_RETENTION as f64, initial_state)
+    }
 }
 
-/// Represents a transform that can be proceeded by another one.
-pub trait Scaffold: Sized {
-    /// Finishes the current series of transformations.
-    fn drive(self, time: f32) -> Mat4;
+impl TransformComponent<Rotate> {
+    /// Creates a new `TransformComponent<Rotate>` with a retention of
+    /// [`DEFAULT_ROTATE_RETENTION`].
+    pub const fn new_rotate(initial_state: Quat) -> Self {
+        Self::new(DEFAULT_ROTATE_RETENTION, initial_state)
+    }
 
-    /// Queues another transformation to happen after the previous one(s).
+    /// Replaces `target` with only its twist about `axis`, and then drives
+    /// the rotation like [`drive`](Self::drive).
+    ///
+    /// This lets, for example, a turret yaw towards a target orientation
+    /// without pitching. See [`Rotate::swing_twist`] for the decomposition;
+    /// `axis` must be normalized.
+    pub fn drive_constrained_axis(&mut self, axis: Vec3, delta_time: f32) -> Quat {
+        self.target = Rotate::swing_twist(self.target, axis).1;
+        self.drive(delta_time)
+    }
+
+    /// How far `current` has drifted from unit length, measured as
+    /// `|length_squared - 1|`.
+    ///
+    /// Driving always renormalizes its result, so drift only builds up when
+    /// `current` is written to directly, for example by accumulating
+    /// rotations onto it.
+    pub fn quat_error(&self) -> f32 {
+        (self.current.length_squared() - 1.0).abs()
+    }
+
+    /// Renormalizes `current` if its [`quat_error`](Self::quat_error) exceeds
+    /// `renorm_epsilon`, returning whether it did.
+    ///
+    /// Checking against a threshold lets long running sessions skip the
+    /// normalization while the drift is negligible;
+    /// [`DEFAULT_RENORM_EPSILON`] is a sensible choice.
+    pub fn renormalize(&mut self, renorm_epsilon: f32) -> bool {
+        if self.quat_error() > renorm_epsilon {
+            self.current = self.current.normalize();
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// The floating point types attributes can be interpolated with,
+/// namely [`f32`] and [`f64`].
+///
+/// Retentions and percents use this type, so that `f64` attributes don't
+/// lose precision to an `f32` percent. Delta times and distances stay `f32`.
+pub trait Scalar: Copy + PartialOrd + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+    + core::fmt::Debug + core::fmt::Display {
+    /// Zero.
+    const ZERO: Self;
+    /// One.
+    const ONE: Self;
+    /// Converts an `f32`, such as a delta time, into this type.
+    fn from_f32(value: f32) -> Self;
+    /// Converts this value into an `f32`, possibly losing precision.
+    fn to_f32(self) -> f32;
+    /// Raises this value to the power of `n`.
+    fn powf(self, n: Self) -> Self;
+    /// Restricts this value to `[min, max]`.
+    fn clamp(self, min: Self, max: Self) -> Self;
+}
+
+macro_rules! impl_scalar {
+    ($($ty:ty),*) => {
+        $(
+            impl Scalar for $ty {
+                const ZERO: Self = 0.0;
+                const ONE: Self = 1.0;
+                fn from_f32(value: f32) -> Self {
+                    value as $ty
+                }
+                fn to_f32(self) -> f32 {
+                    self as f32
+                }
+                fn powf(self, n: Self) -> Self {
+                    <$ty>::powf(self, n)
+                }
+                fn clamp(self, min: Self, max: Self) -> Self {
+                    <$ty>::clamp(self, min, max)
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar!(f32, f64);
+
+/// Describes values with a euclidean length, used to measure
+/// how far apart two [`Translate`] attributes are.
+pub trait Magnitude {
+    /// The floating point type the value is made of.
+    type Scalar: Scalar;
+    /// The euclidean length of this value.
+    fn magnitude(self) -> f32;
+
+    /// The dot product of this value with `other`.
+    fn dot(self, other: Self) -> f32;
+
+    /// How [`Translate`] and [`Spring`] report this value through
+    /// [`drive_verbose`](Scaffold::drive_verbose), which is
+    /// [`DrivenValue::Other`] by default. The built-in types forward to
+    /// [`IntoDrivenValue`].
     #[inline(always)]
-    fn and_then<'a, T: Smoothed, F: FnOnce(T::Attribute) -> Mat4>(self, next: &'a mut TransformComponent<T>, f: F) -> Composition<'a, T, F, Self>
-        where Self: 'a {
-        Composition {
-            component: next,
-            f,
-            inner: self,
+    fn driven_value(self) -> DrivenValue where Self: Sized {
+        DrivenValue::Other
+    }
+}
+
+impl Magnitude for f32 {
+    type Scalar = f32;
+    fn magnitude(self) -> f32 {
+        self.abs()
+    }
+
+    fn dot(self, other: f32) -> f32 {
+        self * other
+    }
+
+    fn driven_value(self) -> DrivenValue {
+        self.into_driven_value()
+    }
+}
+
+impl Magnitude for f64 {
+    type Scalar = f64;
+    fn magnitude(self) -> f32 {
+        self.abs() as f32
+    }
+
+    fn dot(self, other: f64) -> f32 {
+        (self * other) as f32
+    }
+
+    fn driven_value(self) -> DrivenValue {
+        self.into_driven_value()
+    }
+}
+
+macro_rules! impl_magnitude {
+    ($scalar:ty => $($ty:ty),*) => {
+        $(
+            impl Magnitude for $ty {
+                type Scalar = $scalar;
+                fn magnitude(self) -> f32 {
+                    self.length() as f32
+                }
+
+                fn dot(self, other: Self) -> f32 {
+                    <$ty>::dot(self, other) as f32
+                }
+
+                fn driven_value(self) -> DrivenValue {
+                    self.into_driven_value()
+                }
+            }
+        )*
+    };
+}
+
+impl_magnitude!(f32 => Vec2, Vec3, Vec3A, Vec4);
+impl_magnitude!(f64 => DVec2, DVec3, DVec4);
+
+/// Represents anything whose interpolation looks like `(1 - t) * a + t * (b - a)`.
+///
+/// The identity of a translation is its [`Default`], namely zero. The percent
+/// is clamped into `[0, 1]`, so a translation never overshoots its target.
+///
+/// Values made of `f64`, such as [`DVec3`], are interpolated with an `f64`
+/// percent and retention, as determined by their [`Magnitude::Scalar`].
+pub struct Translate<T>(PhantomData<T>);
+
+impl<T> Smoothed for Translate<T>
+where T: Add<T, Output = T> + Mul<T::Scalar, Output = T> + Sub<T, Output = T> + Magnitude + Default + Copy {
+    type Attribute = T;
+    type Scalar = T::Scalar;
+    fn drive(target: T, current: T, percent: T::Scalar) -> T {
+        current + (target - current) * percent.clamp(T::Scalar::ZERO, T::Scalar::ONE)
+    }
+
+    fn distance(a: T, b: T) -> f32 {
+        (b - a).magnitude()
+    }
+
+    fn driven_value(value: T) -> DrivenValue {
+        value.driven_value()
+    }
+
+    fn difference(from: T, to: T) -> T {
+        to - from
+    }
+
+    fn identity() -> T {
+        T::default()
+    }
+}
+
+/// Represents quaternion interpolation through [`slerp`](Quat::slerp).
+///
+/// `q` and `-q` describe the same rotation, so the target is kept on the
+/// same hemisphere as the current value to always take the shortest arc.
+/// The target is also renormalized on every drive, so accumulating it
+/// through repeated `target *= rotation` doesn't let it drift off unit length.
+/// Should the interpolated result degenerate, the component snaps to the
+/// target rather than letting NaN into the matrix.
+///
+/// Zero or non-finite quaternions, as produced by a bad decomposition for
+/// example, don't describe a rotation. A degenerate target is replaced by
+/// the current value and vice versa, falling back to [`Quat::IDENTITY`] if
+/// both are degenerate, so the component recovers instead of turning `NaN`.
+pub struct Rotate;
+
+impl Rotate {
+    /// Splits `rotation` into a swing and a twist, such that
+    /// `rotation = swing * twist`, where the twist rotates about `axis` and
+    /// the swing rotates about an axis perpendicular to it.
+    ///
+    /// `axis` must be normalized. When `rotation` turns `axis` exactly
+    /// around, the twist is undefined and [`Quat::IDENTITY`] is returned.
+    pub fn swing_twist(rotation: Quat, axis: Vec3) -> (Quat, Quat) {
+        let projected = axis * rotation.xyz().dot(axis);
+        let twist = Self::sanitize(Quat::from_xyzw(projected.x, projected.y, projected.z, rotation.w))
+            .unwrap_or(Quat::IDENTITY);
+        (rotation * twist.inverse(), twist)
+    }
+
+    /// Normalizes `quat`, unless it is zero or not finite and so doesn't
+    /// describe a rotation at all.
+    fn sanitize(quat: Quat) -> Option<Quat> {
+        let length_squared = quat.length_squared();
+        (length_squared.is_finite() && length_squared > 1e-12)
+            .then(|| quat * length_squared.sqrt().recip())
+    }
+}
+
+impl Smoothed for Rotate {
+    type Attribute = Quat;
+    type Scalar = f32;
+    fn drive(target: Quat, current: Quat, percent: f32) -> Quat {
+        let current = Self::sanitize(current)
+            .or_else(|| Self::sanitize(target))
+            .unwrap_or(Quat::IDENTITY);
+        let target = Self::align(target, current);
+        Self::sanitize(current.slerp(target, percent)).unwrap_or(target)
+    }
+
+    fn distance(a: Quat, b: Quat) -> f32 {
+        a.angle_between(b)
+    }
+
+    fn difference(from: Quat, to: Quat) -> Quat {
+        to * from.inverse()
+    }
+
+    fn identity() -> Quat {
+        Quat::IDENTITY
+    }
+
+    fn driven_value(value: Quat) -> DrivenValue {
+        value.into()
+    }
+
+    fn align(target: Quat, current: Quat) -> Quat {
+        let target = Self::sanitize(target)
+            .or_else(|| Self::sanitize(current))
+            .unwrap_or(Quat::IDENTITY);
+        if current.dot(target) < 0.0 {
+            -target
+        } else {
+            target
+        }
+    }
+}
+
+/// Represents double precision quaternion interpolation, like [`Rotate`]
+/// but for [`DQuat`].
+///
+/// Degenerate quaternions are handled the same way as for [`Rotate`].
+/// Combined with [`Translate<DVec3>`](Translate) and a
+/// [`DMat4`](glam::DMat4) output, whole chains can run in `f64`:
+/// ```
+/// # use glam::{DMat4, DQuat, DVec3};
+/// # use buttery::{Scaffold, TransformComponent, Translate};
+/// let mut position = TransformComponent::<Translate<DVec3>>::new(0.01, DVec3::splat(1e9));
+/// let mut rotation = TransformComponent::new_rotate64(DQuat::IDENTITY);
+/// position.target += DVec3::X;
+///
+/// let transform: DMat4 = position.begin(DMat4::from_translation)
+///     .and_then(&mut rotation, DMat4::from_quat)
+///     .drive(0.016);
+/// # assert!(transform.is_finite());
+/// ```
+pub struct Rotate64;
+
+impl Rotate64 {
+    fn sanitize(quat: DQuat) -> Option<DQuat> {
+        let length_squared = quat.length_squared();
+        (length_squared.is_finite() && length_squared > 1e-24)
+            .then(|| quat * length_squared.sqrt().recip())
+    }
+}
+
+impl Smoothed for Rotate64 {
+    type Attribute = DQuat;
+    type Scalar = f64;
+    fn drive(target: DQuat, current: DQuat, percent: f64) -> DQuat {
+        let current = Self::sanitize(current)
+            .or_else(|| Self::sanitize(target))
+            .unwrap_or(DQuat::IDENTITY);
+        let target = Self::align(target, current);
+        Self::sanitize(current.slerp(target, percent)).unwrap_or(target)
+    }
+
+    fn distance(a: DQuat, b: DQuat) -> f32 {
+        a.angle_between(b) as f32
+    }
+
+    fn difference(from: DQuat, to: DQuat) -> DQuat {
+        to * from.inverse()
+    }
+
+    fn identity() -> DQuat {
+        DQuat::IDENTITY
+    }
+
+    fn driven_value(value: DQuat) -> DrivenValue {
+        value.into()
+    }
+
+    fn align(target: DQuat, current: DQuat) -> DQuat {
+        let target = Self::sanitize(target)
+            .or_else(|| Self::sanitize(current))
+            .unwrap_or(DQuat::IDENTITY);
+        if current.dot(target) < 0.0 {
+            -target
+        } else {
+            target
         }
     }
 }
 
-impl<'a, T: Smoothed, F: FnOnce(T::Attribute) -> Mat4> Scaffold for First<'a, T, F> {
+/// Composes two transforms such that `inner` is applied first, and then `outer`.
+///
+/// This is the same convention [`and_then`](Scaffold::and_then) uses: each
+/// queued stage is the `outer` of everything that came before it. Use this when
+/// combining matrices driven separately, for example in a following camera:
+/// ```
+/// # use glam::{Mat4, Vec3};
+/// # use buttery::{compose, Scaffold, TransformComponent, Translate};
+/// let mut position = TransformComponent::new_translate(Vec3::ZERO);
+/// let mut target = TransformComponent::<Translate<Vec3>>::new(0.001, Vec3::X);
+/// position.target += Vec3::X;
+/// target.target += Vec3::NEG_Y;
+///
+/// let delta_time = 0.016;
+/// let camera_position = position.drive(delta_time);
+/// let partial = target.begin(|target_pos| Mat4::look_at_rh(camera_position, target_pos, Vec3::Y))
+///     .drive(delta_time);
+///
+/// let transform = compose(partial, Mat4::from_translation(camera_position));
+/// assert_eq!(transform, partial * Mat4::from_translation(camera_position));
+/// let view_matrix = transform.inverse();
+/// # assert!(view_matrix.is_finite());
+/// ```
+///
+/// Any output supported by [`Scaffold`] can be composed, such as
+/// [`Affine3A`](glam::Affine3A) as well as [`Mat4`].
+#[inline(always)]
+pub fn compose<O: Mul<Output = O>>(outer: O, inner: O) -> O {
+    outer * inner
+}
+
+/// Represents a unit direction vector, which is interpolated along the
+/// great circle towards its target at a constant angular rate.
+///
+/// Unlike lerping and renormalizing, this never shortens the vector and
+/// doesn't speed up in the middle of the arc. When the directions are exactly
+/// opposite every great circle is equally short, and an arbitrary but
+/// consistent one is picked through [`Quat::from_rotation_arc`].
+///
+/// The identity is [`Vec3::NEG_Z`], the forward direction in right handed
+/// coordinates, which also stands in for zero or non-finite vectors, so they
+/// can't poison the component with NaN.
+///
+/// Differences are the chord `to - from`, not a tangent space delta: adding
+/// the difference onto `from` lands on `to`, but scaling it doesn't move a
+/// proportional angle along the arc.
+pub struct Direction;
+
+impl Smoothed for Direction {
+    type Attribute = Vec3;
+    type Scalar = f32;
+    fn drive(target: Vec3, current: Vec3, percent: f32) -> Vec3 {
+        let current = current.normalize_or(Self::identity());
+        let arc = Quat::from_rotation_arc(current, Self::align(target, current));
+        (Quat::IDENTITY.slerp(arc, percent) * current).normalize_or(Self::identity())
+    }
+
+    fn align(target: Vec3, _current: Vec3) -> Vec3 {
+        target.normalize_or(Self::identity())
+    }
+
+    fn distance(a: Vec3, b: Vec3) -> f32 {
+        a.normalize_or(Self::identity()).angle_between(b.normalize_or(Self::identity()))
+    }
+
+    fn difference(from: Vec3, to: Vec3) -> Vec3 {
+        to - from
+    }
+
+    fn identity() -> Vec3 {
+        Vec3::NEG_Z
+    }
+
+    fn driven_value(value: Vec3) -> DrivenValue {
+        value.into()
+    }
+}
+
+/// Represents a planar rotation angle in radians, which always
+/// interpolates along the shortest way around the circle.
+///
+/// Values are kept wrapped into `[-π, π)`, so an angle going from `0.1`
+/// to `6.2` turns backwards by about `0.18` instead of sweeping forwards.
+pub struct Rotate2D;
+
+/// A wrapped angle in radians, the same as [`Rotate2D`].
+pub type Angle = Rotate2D;
+
+impl Rotate2D {
+    fn wrap(angle: f32) -> f32 {
+        (angle + PI).rem_euclid(TAU) - PI
+    }
+}
+
+impl Smoothed for Rotate2D {
+    type Attribute = f32;
+    type Scalar = f32;
+    fn drive(target: f32, current: f32, percent: f32) -> f32 {
+        Self::wrap(current + Self::wrap(target - current) * percent)
+    }
+
+    fn distance(a: f32, b: f32) -> f32 {
+        Self::wrap(b - a).abs()
+    }
+
+    fn difference(from: f32, to: f32) -> f32 {
+        Self::wrap(to - from)
+    }
+
+    fn identity() -> f32 {
+        0.0
+    }
+
+    fn driven_value(value: f32) -> DrivenValue {
+        value.into()
+    }
+}
+
+/// Represents a rotation as Euler angles in radians, each of which
+/// interpolates independently along its shortest way around the circle.
+///
+/// The angles are stored as `(yaw, pitch, roll)` and applied in the
+/// [`EulerRot::YXZ`] order: yaw about the Y axis, then pitch about the
+/// local X axis, then roll about the local Z axis. Use [`to_quat`](Self::to_quat)
+/// to convert when composing:
+/// ```
+/// # use glam::{Mat4, Vec3};
+/// # use buttery::{RotateEuler, Scaffold, TransformComponent};
+/// let mut angles = TransformComponent::<RotateEuler>::new(0.04, Vec3::ZERO);
+/// angles.target.x = 1.0;
+/// let transform = angles.begin(|angles| Mat4::from_quat(RotateEuler::to_quat(angles)))
+///     .drive(0.016);
+/// # assert!(transform.is_finite());
+/// ```
+///
+/// This matches how rotations are usually authored, but isn't the shortest
+/// path between the two orientations; prefer [`Rotate`] when that matters.
+pub struct RotateEuler;
+
+impl RotateEuler {
+    /// Converts `(yaw, pitch, roll)` angles into a quaternion.
+    pub fn to_quat(angles: Vec3) -> Quat {
+        Quat::from_euler(EulerRot::YXZ, angles.x, angles.y, angles.z)
+    }
+
+    /// Converts a quaternion into `(yaw, pitch, roll)` angles.
+    pub fn from_quat(quat: Quat) -> Vec3 {
+        let (yaw, pitch, roll) = quat.to_euler(EulerRot::YXZ);
+        Vec3::new(yaw, pitch, roll)
+    }
+
+    fn wrap(angles: Vec3) -> Vec3 {
+        Vec3::new(Rotate2D::wrap(angles.x), Rotate2D::wrap(angles.y), Rotate2D::wrap(angles.z))
+    }
+}
+
+impl Smoothed for RotateEuler {
+    type Attribute = Vec3;
+    type Scalar = f32;
+    fn drive(target: Vec3, current: Vec3, percent: f32) -> Vec3 {
+        Self::wrap(current + Self::wrap(target - current) * percent)
+    }
+
+    fn distance(a: Vec3, b: Vec3) -> f32 {
+        Self::wrap(b - a).length()
+    }
+
+    fn difference(from: Vec3, to: Vec3) -> Vec3 {
+        Self::wrap(to - from)
+    }
+
+    fn identity() -> Vec3 {
+        Vec3::ZERO
+    }
+
+    fn driven_value(value: Vec3) -> DrivenValue {
+        value.into()
+    }
+}
+
+/// Represents geometric interpolation, looking like `a * (b / a)^t`.
+///
+/// Equal amounts of time produce equal multiplicative changes, which is
+/// what zooming and scaling usually should feel like. Zero or negative
+/// scales don't have a logarithm, so when driving, both values are clamped
+/// to at least [`MIN_SCALE`] instead of producing `NaN`.
+///
+/// Distances are measured in log space too, so a distance of `0.01`
+/// means the values are about 1% apart regardless of their magnitude.
+/// They are clamped the same way, so [`remaining`](TransformComponent::remaining)
+/// stays finite, while [`validate`](TransformComponent::validate) still
+/// reports scales outside of the domain.
+pub struct Scale<T = f32>(PhantomData<T>);
+
+impl Smoothed for Scale<f32> {
+    type Attribute = f32;
+    type Scalar = f32;
+    fn drive(target: f32, current: f32, percent: f32) -> f32 {
+        let current = current.max(MIN_SCALE);
+        current * (target.max(MIN_SCALE) / current).powf(percent)
+    }
+
+    fn distance(a: f32, b: f32) -> f32 {
+        (b.max(MIN_SCALE) / a.max(MIN_SCALE)).ln().abs()
+    }
+
+    fn difference(from: f32, to: f32) -> f32 {
+        to / from
+    }
+
+    fn identity() -> f32 {
+        1.0
+    }
+
+    fn is_valid(value: f32) -> bool {
+        value.is_finite() && value > 0.0
+    }
+
+    fn driven_value(value: f32) -> DrivenValue {
+        value.into()
+    }
+}
+
+impl Smoothed for Scale<Vec3> {
+    type Attribute = Vec3;
+    type Scalar = f32;
+    fn drive(target: Vec3, current: Vec3, percent: f32) -> Vec3 {
+        let current = current.max(Vec3::splat(MIN_SCALE));
+        current * (target.max(Vec3::splat(MIN_SCALE)) / current).powf(percent)
+    }
+
+    fn distance(a: Vec3, b: Vec3) -> f32 {
+        let ratio = b.max(Vec3::splat(MIN_SCALE)) / a.max(Vec3::splat(MIN_SCALE));
+        Vec3::new(ratio.x.ln(), ratio.y.ln(), ratio.z.ln()).length()
+    }
+
+    fn difference(from: Vec3, to: Vec3) -> Vec3 {
+        to / from
+    }
+
+    fn identity() -> Vec3 {
+        Vec3::ONE
+    }
+
+    fn is_valid(value: Vec3) -> bool {
+        value.is_finite() && value.cmpgt(Vec3::ZERO).all()
+    }
+
+    fn driven_value(value: Vec3) -> DrivenValue {
+        value.into()
+    }
+}
+
+/// Adds `delta_time` onto `remainder`, and takes off as many whole `step`s
+/// as fit, returning how many.
+///
+/// At most [`MAX_FIXED_SUBSTEPS`] are taken, and any time beyond them is
+/// dropped along with a remainder that isn't a number.
+pub(crate) fn fixed_steps(remainder: &mut f32, delta_time: f32, step: f32) -> u32 {
+    *remainder += delta_time;
+    let steps = (*remainder / step).floor();
+    if steps.is_nan() || steps >= MAX_FIXED_SUBSTEPS as f32 {
+        *remainder = 0.0;
+        return if steps.is_nan() { 0 } else { MAX_FIXED_SUBSTEPS };
+    }
+    if steps < 1.0 {
+        return 0;
+    }
+
+    let mut steps = steps as u32;
+    *remainder = (*remainder - steps as f32 * step).max(0.0);
+    // The division may round down by one.
+    if *remainder >= step {
+        *remainder -= step;
+        steps += 1;
+    }
+    steps
+}
+
+/// The time to percent mapping used by [`drive`](TransformComponent::drive):
+/// the fraction of the way to the target to move after `delta_time`
+/// seconds, given a `retention`.
+///
+/// This is `1 - retention^delta_time`, and can be passed to
+/// [`drive_with`](TransformComponent::drive_with) to get the default
+/// behaviour.
+pub fn exponential_percent<S: Scalar>(retention: S, delta_time: S) -> S {
+    S::ONE - retention.powf(delta_time)
+}
+
+/// The retention of a single stage which behaves like two cascaded stages
+/// with retentions `a` and `b`, where the second follows the first's `current`.
+///
+/// A stage with retention `r` has the impulse response of a one-pole
+/// filter with time constant `τ = -1 / ln(r)`, which is also its mean delay.
+/// The impulse response of a cascade is the convolution of its stages, whose
+/// mean delay is the sum `τa + τb`. The single stage with the same mean delay
+/// lags behind a moving target by the same total amount, although the
+/// cascade starts more gently and catches up more sharply.
+pub fn combined_retention(a: f32, b: f32) -> f32 {
+    (1.0 / (1.0 / a.ln() + 1.0 / b.ln())).exp()
+}
+
+/// The inverse of [`combined_retention`]: the retention to give each of two
+/// identical cascaded stages so that together they behave like a single
+/// stage with retention `combined`.
+///
+/// Each stage takes half the mean delay, which works out to `combined²`.
+pub fn split_retention(combined: f32) -> f32 {
+    combined * combined
+}
+
+/// Anything which can be driven as a stage of a [`Scaffold`] chain, such
+/// as a [`TransformComponent`] or a [`Spring`].
+///
+/// This lets differently smoothed values be mixed in one composition:
+/// ```
+/// # use glam::{Mat4, Quat, Vec3};
+/// # use buttery::{Scaffold, Spring, TransformComponent};
+/// let mut position = Spring::new(0.2, Vec3::ZERO);
+/// let mut rotation = TransformComponent::new_rotate(Quat::IDENTITY);
+/// position.target = Vec3::X;
+///
+/// let transform = position.begin(Mat4::from_translation)
+///     .and_then(&mut rotation, Mat4::from_quat)
+///     .drive(0.016);
+/// # assert!(transform.is_finite());
+/// ```
+pub trait Stage {
+    /// The value this stage produces.
+    type Attribute: Copy;
+
+    /// Drives the stage forward by `delta_time` seconds.
+    fn drive(&mut self, delta_time: f32) -> Self::Attribute;
+
+    /// Returns what [`drive`](Self::drive) would produce, without
+    /// modifying the stage.
+    fn peek(&self, delta_time: f32) -> Self::Attribute;
+
+    /// Interpolates from the current value towards the target by `t`,
+    /// without modifying the stage.
+    fn at_fraction(&self, t: f32) -> Self::Attribute;
+
+    /// Drives the stage in fixed substeps of `step` seconds, carrying the
+    /// remainder of `delta_time` over to the next call.
+    ///
+    /// See [`TransformComponent::drive_fixed`].
+    fn drive_fixed(&mut self, delta_time: f32, step: f32) -> Self::Attribute;
+
+    /// Whether the current value has come within `epsilon` of the target.
+    fn is_settled(&self, epsilon: f32) -> bool;
+
+    /// Converts a value into a [`DrivenValue`] for inspection through
+    /// [`drive_verbose`](Scaffold::drive_verbose). By default this is
+    /// [`DrivenValue::Other`].
     #[inline(always)]
-    fn drive(self, time: f32) -> Mat4 {
-        let attrib = self.component.drive(time);
-        (self.f)(attrib)
+    fn driven_value(_value: Self::Attribute) -> DrivenValue {
+        DrivenValue::Other
     }
 }
 
-impl<'a, T, F, I> Scaffold for Composition<'a, T, F, I>
-where T: Smoothed,
-    F: FnOnce(T::Attribute) -> Mat4,
-    I: Scaffold + 'a {
+impl<T: Smoothed> Stage for TransformComponent<T> {
+    type Attribute = T::Attribute;
     #[inline(always)]
-    fn drive(self, time: f32) -> Mat4 {
-        let inner = self.inner.drive(time);
-        let attrib = self.component.drive(time);
-        (self.f)(attrib) * inner
+    fn drive(&mut self, delta_time: f32) -> T::Attribute {
+        TransformComponent::drive(self, delta_time)
+    }
+    #[inline(always)]
+    fn peek(&self, delta_time: f32) -> T::Attribute {
+        TransformComponent::peek(self, delta_time)
+    }
+    #[inline(always)]
+    fn at_fraction(&self, t: f32) -> T::Attribute {
+        TransformComponent::at_fraction(self, t)
+    }
+    #[inline(always)]
+    fn drive_fixed(&mut self, delta_time: f32, step: f32) -> T::Attribute {
+        TransformComponent::drive_fixed(self, delta_time, step)
+    }
+    #[inline(always)]
+    fn is_settled(&self, epsilon: f32) -> bool {
+        TransformComponent::is_settled(self, epsilon)
+    }
+    #[inline(always)]
+    fn driven_value(value: T::Attribute) -> DrivenValue {
+        T::driven_value(value)
     }
 }
 
-#[cfg(test)]
-mod test {
-    use glam::Vec3;
-    use super::*;
+/// Drives several independent [`Stage`]s once each by the same
+/// `delta_time`, returning their attributes as a tuple in order.
+///
+/// The stages may be of different types. Since each is taken by `&mut`,
+/// passing the same stage twice, which would silently double its
+/// convergence rate, is rejected by the borrow checker.
+/// ```
+/// # use glam::{Quat, Vec3};
+/// # use buttery::{Spring, TransformComponent};
+/// let mut position = TransformComponent::new_translate(Vec3::ZERO);
+/// let mut rotation = TransformComponent::new_rotate(Quat::IDENTITY);
+/// let mut zoom = Spring::new(0.2, 1.0);
+/// position.target = Vec3::X;
+///
+/// let (position, rotation, zoom) =
+///     buttery::drive_all!(0.016; &mut position, &mut rotation, &mut zoom);
+/// # assert!(position.x > 0.0 && rotation.is_normalized() && zoom == 1.0);
+/// ```
+#[macro_export]
+macro_rules! drive_all {
+    ($delta_time:expr; $($stage:expr),+ $(,)?) => {{
+        let delta_time: f32 = $delta_time;
+        ($($crate::Stage::drive($stage, delta_time),)+)
+    }};
+}
 
-    #[test]
-    fn this_works() {
-        let mut zoom = TransformComponent::new_zoom(2.0);
-        let mut rotate = TransformComponent::new_rotate(Quat::IDENTITY);
-        let mut translate = TransformComponent::new_translate(Vec3::ONE);
-        let mut angle = TransformComponent::new_angle(0.4);
+/// Implementation detail. Yielded from [`.begin`](TransformComponent::begin).
+pub struct First<'a, S: Stage, F: FnOnce(S::Attribute) -> O, O = Mat4> {
+    component: &'a mut S,
+    f: F,
+    _output: PhantomData<fn() -> O>,
+}
 
-        zoom.target = 4.0;
-        rotate.target *= Quat::from_rotation_x(0.4);
-        translate.target += Vec3::ONE;
-        angle.target /= 3.0;
+impl<'a, S: Stage, F: FnOnce(S::Attribute) -> O, O> First<'a, S, F, O> {
+    pub(crate) fn new(component: &'a mut S, f: F) -> Self {
+        Self {
+            component,
+            f,
+            _output: PhantomData,
+        }
+    }
+}
 
-        let delta_time = 0.03;
+/// Result of calling [`.and_then`](Scaffold::and_then).
+pub struct Composition<'a, S: Stage, F: FnOnce(S::Attribute) -> I::Output, I: Scaffold + 'a> {
+    component: &'a mut S,
+    f: F,
+    inner: I,
+}
 
-        let transform_matrix = zoom.begin(|zoom| Mat4::from_translation(Vec3::splat(zoom)))
-            .and_then(&mut rotate, |quat| Mat4::from_quat(quat))
-            .and_then(&mut translate, |by| Mat4::from_translation(by))
-            .and_then(&mut angle, |angle| Mat4::from_rotation_y(angle))
-            .drive(delta_time);
+/// Result of calling [`.and_then_before`](Scaffold::and_then_before).
+pub struct CompositionBefore<'a, S: Stage, F: FnOnce(S::Attribute) -> I::Output, I: Scaffold + 'a> {
+    component: &'a mut S,
+    f: F,
+    inner: I,
+}
+
+/// Result of calling [`.in_parent`](Scaffold::in_parent).
+pub struct InParent<I: Scaffold> {
+    parent_world: I::Output,
+    inner: I,
+}
+
+/// Result of calling [`.about_pivot`](Scaffold::about_pivot).
+pub struct AboutPivot<I: Scaffold> {
+    pivot: Vec3,
+    inner: I,
+}
+
+/// Represents a transform that can be proceeded by another one.
+///
+/// Stages produce and compose an [`Output`](Self::Output), which is
+/// whatever the closure passed to [`begin`](TransformComponent::begin)
+/// returns, usually a [`Mat4`]:
+/// ```
+/// # use glam::{Affine3A, Quat, Vec3};
+/// # use buttery::{Scaffold, TransformComponent};
+/// let mut position = TransformComponent::new_translate(Vec3::ZERO);
+/// let mut rotation = TransformComponent::new_rotate(Quat::IDENTITY);
+///
+/// let transform: Affine3A = position.begin(|position| Affine3A::from_translation(position))
+///     .and_then(&mut rotation, Affine3A::from_quat)
+///     .drive(0.016);
+/// # assert!(transform.is_finite());
+/// ```
+pub trait Scaffold: Sized {
+    /// The transform each stage produces, such as [`Mat4`].
+    type Output: Mul<Output = Self::Output> + Copy;
+
+    /// The attributes every stage drives to, as yielded by
+    /// [`drive_raw`](Self::drive_raw).
+    type Raw;
+
+    /// Finishes the current series of transformations.
+    fn drive(self, time: f32) -> Self::Output;
+
+    /// Drives every component like [`drive`](Self::drive), but returns the
+    /// attributes themselves instead of composing them, skipping the closures.
+    ///
+    /// The attributes are nested in pairs in the order the stages were
+    /// added, so three stages yield `((a, b), c)`:
+    /// ```
+    /// # use glam::{Mat4, Quat, Vec3};
+    /// # use buttery::{Scaffold, TransformComponent};
+    /// let mut zoom = TransformComponent::new_zoom(1.0);
+    /// let mut rotation = TransformComponent::new_rotate(Quat::IDENTITY);
+    /// let mut position = TransformComponent::new_translate(Vec3::ZERO);
+    ///
+    /// let ((zoom, rotation), position) = zoom.begin(|zoom| Mat4::from_scale(Vec3::splat(zoom)))
+    ///     .and_then(&mut rotation, Mat4::from_quat)
+    ///     .and_then(&mut position, Mat4::from_translation)
+    ///     .drive_raw(0.016);
+    /// # assert_eq!((zoom, rotation, position), (1.0, Quat::IDENTITY, Vec3::ZERO));
+    /// ```
+    ///
+    /// Components which aren't composed at all can be driven together
+    /// without any closures through [`drive_all!`](crate::drive_all).
+    fn drive_raw(self, time: f32) -> Self::Raw;
+
+    /// Evaluates the series of transformations with every component
+    /// interpolated by `t` from its `current` towards its `target`, without
+    /// modifying any of them.
+    ///
+    /// A `t` of `0.0` yields the matrix for the current values, and `1.0`
+    /// the matrix for the targets, which is useful for scrubbing timelines.
+    fn at_fraction(self, t: f32) -> Self::Output;
+
+    /// Evaluates the series of transformations as [`drive`](Self::drive)
+    /// would after `time` seconds, without modifying any component.
+    ///
+    /// See [`TransformComponent::peek`].
+    fn peek(self, time: f32) -> Self::Output;
+
+    /// Finishes the current series of transformations like
+    /// [`drive`](Self::drive), but drives every component in fixed
+    /// substeps of `step` seconds.
+    ///
+    /// Each component carries its own remainder, and since they are all
+    /// handed the same times they stay in lockstep. See
+    /// [`TransformComponent::drive_fixed`].
+    fn drive_fixed(self, time: f32, step: f32) -> Self::Output;
+
+    /// Whether every component in the series of transformations is
+    /// [settled](TransformComponent::is_settled) within `epsilon`.
+    fn is_settled(&self, epsilon: f32) -> bool;
+
+    /// Finishes the current series of transformations like
+    /// [`drive`](Self::drive), and pushes each stage's driven attribute onto
+    /// `values`, starting with the first stage.
+    ///
+    /// This backs [`drive_verbose`](Self::drive_verbose). Implementations
+    /// wrapping other chains should forward it; by default nothing is pushed.
+    fn drive_collecting<E: Extend<DrivenValue>>(self, time: f32, _values: &mut E) -> Self::Output {
+        self.drive(time)
+    }
+
+    /// Finishes the current series of transformations like
+    /// [`drive`](Self::drive), and also returns every stage's driven
+    /// attribute, starting with the first stage, for debug overlays.
+    ///
+    /// Attributes are type erased through [`Smoothed::driven_value`], and
+    /// collected into any `C`, such as a [`Vec`] or a fixed capacity buffer:
+    /// ```
+    /// # use glam::{Mat4, Quat, Vec3};
+    /// # use buttery::{DrivenValue, Scaffold, TransformComponent};
+    /// let mut position = TransformComponent::new_translate(Vec3::ZERO);
+    /// let mut rotation = TransformComponent::new_rotate(Quat::IDENTITY);
+    ///
+    /// let (_, values): (_, Vec<_>) = position.begin(Mat4::from_translation)
+    ///     .and_then(&mut rotation, Mat4::from_quat)
+    ///     .drive_verbose(0.016);
+    /// assert_eq!(values, [DrivenValue::Vec3(Vec3::ZERO), DrivenValue::Quat(Quat::IDENTITY)]);
+    /// ```
+    fn drive_verbose<C: Default + Extend<DrivenValue>>(self, time: f32) -> (Self::Output, C) {
+        let mut values = C::default();
+        let matrix = self.drive_collecting(time, &mut values);
+        (matrix, values)
+    }
+
+    /// Finishes the current series of transformations like
+    /// [`drive`](Self::drive), and also returns the normal matrix, namely
+    /// the inverse transpose of the upper 3x3 of the resulting matrix.
+    ///
+    /// When the matrix only rotates and scales uniformly, the normal matrix
+    /// is found directly by dividing out the scale instead of inverting.
+    fn drive_with_normal(self, time: f32) -> (Mat4, Mat3)
+        where Self: Scaffold<Output = Mat4> {
+        let matrix = self.drive(time);
+        let upper = Mat3::from_mat4(matrix);
+
+        let scale_squared = upper.x_axis.length_squared();
+        let uniform = [upper.y_axis, upper.z_axis]
+            .iter()
+            .all(|axis| (axis.length_squared() - scale_squared).abs() <= scale_squared * 1e-5)
+            && [upper.x_axis.dot(upper.y_axis), upper.y_axis.dot(upper.z_axis), upper.z_axis.dot(upper.x_axis)]
+            .iter()
+            .all(|dot| dot.abs() <= scale_squared * 1e-5);
+
+        let normal = if uniform && scale_squared > 0.0 {
+            upper * scale_squared.recip()
+        } else {
+            upper.inverse().transpose()
+        };
+
+        (matrix, normal)
+    }
+
+    /// Queues another transformation to happen after the previous one(s).
+    ///
+    /// `next` may be any [`Stage`], such as a [`TransformComponent`] or
+    /// a [`Spring`].
+    #[inline(always)]
+    fn and_then<'a, S: Stage, F: FnOnce(S::Attribute) -> Self::Output>(self, next: &'a mut S, f: F) -> Composition<'a, S, F, Self>
+        where Self: 'a {
+        Composition {
+            component: next,
+            f,
+            inner: self,
+        }
+    }
+
+    /// Queues another transformation to happen before the previous one(s),
+    /// the reverse of [`and_then`](Self::and_then).
+    ///
+    /// Where `and_then` multiplies the new stage on the left, this multiplies
+    /// it on the right, so the first declared stage ends up outermost:
+    /// ```
+    /// # use glam::{Mat4, Quat, Vec3};
+    /// # use buttery::{Scaffold, TransformComponent};
+    /// let mut position = TransformComponent::new_translate(Vec3::X);
+    /// let mut rotation = TransformComponent::new_rotate(Quat::from_rotation_y(1.0));
+    ///
+    /// // Translates, after rotating.
+    /// let transform = position.begin(Mat4::from_translation)
+    ///     .and_then_before(&mut rotation, Mat4::from_quat)
+    ///     .drive(0.016);
+    /// assert_eq!(transform, Mat4::from_translation(position.current) * Mat4::from_quat(rotation.current));
+    /// ```
+    ///
+    /// Stages are still driven, and reported through
+    /// [`drive_raw`](Self::drive_raw) and [`drive_verbose`](Self::drive_verbose),
+    /// in the order they were declared.
+    #[inline(always)]
+    fn and_then_before<'a, S: Stage, F: FnOnce(S::Attribute) -> Self::Output>(self, next: &'a mut S, f: F) -> CompositionBefore<'a, S, F, Self>
+        where Self: 'a {
+        CompositionBefore {
+            component: next,
+            f,
+            inner: self,
+        }
+    }
+
+    /// Queues another transformation like [`and_then`](Self::and_then),
+    /// but only applies `weight` of the attribute's deviation from its
+    /// [`identity`](Smoothed::identity).
+    ///
+    /// The partial attribute is found by interpolating from the identity
+    /// with [`Smoothed::drive`], so a `weight` of `0.0` contributes nothing,
+    /// `1.0` contributes the whole attribute, and for example `0.5` of a
+    /// rotation is a slerp halfway from the identity. The component itself
+    /// is still driven in full.
+    #[inline(always)]
+    fn and_then_weighted<'a, T: Smoothed, F: FnOnce(T::Attribute) -> Self::Output>(self, next: &'a mut TransformComponent<T>, weight: f32, f: F) -> Composition<'a, TransformComponent<T>, impl FnOnce(T::Attribute) -> Self::Output, Self>
+        where Self: 'a {
+        self.and_then(next, move |attrib| f(T::drive(attrib, T::identity(), T::Scalar::from_f32(weight))))
+    }
+
+    /// Places the series of transformations, as a local transform, under
+    /// a parent whose local-to-world matrix is `parent_world`.
+    ///
+    /// This yields `parent_world * local`, the usual scene graph convention:
+    /// points are first moved by the local transform, and then by the parent.
+    /// ```
+    /// # use glam::{Mat4, Quat, Vec3};
+    /// # use buttery::{Scaffold, TransformComponent};
+    /// let mut rotation = TransformComponent::new_rotate(Quat::from_rotation_z(1.0));
+    /// let parent_world = Mat4::from_translation(Vec3::X);
+    ///
+    /// let world = rotation.begin(Mat4::from_quat)
+    ///     .in_parent(parent_world)
+    ///     .drive(0.016);
+    /// assert_eq!(world, parent_world * Mat4::from_quat(rotation.current));
+    /// ```
+    #[inline(always)]
+    fn in_parent(self, parent_world: Self::Output) -> InParent<Self> {
+        InParent {
+            parent_world,
+            inner: self,
+        }
+    }
+
+    /// Applies the series of transformations about `pivot` instead of
+    /// the origin, such as to rotate or scale around a point.
+    ///
+    /// This yields `T(pivot) * local * T(-pivot)`, moving the pivot to the
+    /// origin, transforming, and moving it back. This is only available for
+    /// chains producing a [`Mat4`].
+    /// ```
+    /// # use glam::{Mat4, Quat, Vec3};
+    /// # use buttery::{Scaffold, TransformComponent};
+    /// let mut spin = TransformComponent::new_rotate(Quat::from_rotation_z(std::f32::consts::PI));
+    ///
+    /// let transform = spin.begin(Mat4::from_quat)
+    ///     .about_pivot(Vec3::X)
+    ///     .drive(0.016);
+    /// assert!(transform.transform_point3(Vec3::X).abs_diff_eq(Vec3::X, 1e-5));
+    /// ```
+    #[inline(always)]
+    fn about_pivot(self, pivot: Vec3) -> AboutPivot<Self>
+        where Self: Scaffold<Output = Mat4> {
+        AboutPivot {
+            pivot,
+            inner: self,
+        }
+    }
+}
+
+impl<'a, S, F, O> Scaffold for First<'a, S, F, O>
+where S: Stage,
+    F: FnOnce(S::Attribute) -> O,
+    O: Mul<Output = O> + Copy {
+    type Output = O;
+    type Raw = S::Attribute;
+    #[inline(always)]
+    fn drive(self, time: f32) -> O {
+        let attrib = self.component.drive(time);
+        (self.f)(attrib)
+    }
+    #[inline(always)]
+    fn drive_raw(self, time: f32) -> S::Attribute {
+        self.component.drive(time)
+    }
+    #[inline(always)]
+    fn drive_collecting<E: Extend<DrivenValue>>(self, time: f32, values: &mut E) -> O {
+        let attrib = self.component.drive(time);
+        values.extend(core::iter::once(S::driven_value(attrib)));
+        (self.f)(attrib)
+    }
+    #[inline(always)]
+    fn at_fraction(self, t: f32) -> O {
+        (self.f)(self.component.at_fraction(t))
+    }
+    #[inline(always)]
+    fn peek(self, time: f32) -> O {
+        (self.f)(self.component.peek(time))
+    }
+    #[inline(always)]
+    fn drive_fixed(self, time: f32, step: f32) -> O {
+        (self.f)(self.component.drive_fixed(time, step))
+    }
+    #[inline(always)]
+    fn is_settled(&self, epsilon: f32) -> bool {
+        self.component.is_settled(epsilon)
+    }
+}
+
+impl<'a, S, F, I> Scaffold for Composition<'a, S, F, I>
+where S: Stage,
+    F: FnOnce(S::Attribute) -> I::Output,
+    I: Scaffold + 'a {
+    type Output = I::Output;
+    type Raw = (I::Raw, S::Attribute);
+    #[inline(always)]
+    fn drive(self, time: f32) -> I::Output {
+        let inner = self.inner.drive(time);
+        let attrib = self.component.drive(time);
+        compose((self.f)(attrib), inner)
+    }
+    #[inline(always)]
+    fn drive_raw(self, time: f32) -> Self::Raw {
+        let inner = self.inner.drive_raw(time);
+        (inner, self.component.drive(time))
+    }
+    #[inline(always)]
+    fn drive_collecting<E: Extend<DrivenValue>>(self, time: f32, values: &mut E) -> I::Output {
+        let inner = self.inner.drive_collecting(time, values);
+        let attrib = self.component.drive(time);
+        values.extend(core::iter::once(S::driven_value(attrib)));
+        compose((self.f)(attrib), inner)
+    }
+    #[inline(always)]
+    fn at_fraction(self, t: f32) -> I::Output {
+        let inner = self.inner.at_fraction(t);
+        compose((self.f)(self.component.at_fraction(t)), inner)
+    }
+    #[inline(always)]
+    fn peek(self, time: f32) -> I::Output {
+        let inner = self.inner.peek(time);
+        compose((self.f)(self.component.peek(time)), inner)
+    }
+    #[inline(always)]
+    fn drive_fixed(self, time: f32, step: f32) -> I::Output {
+        let inner = self.inner.drive_fixed(time, step);
+        compose((self.f)(self.component.drive_fixed(time, step)), inner)
+    }
+    #[inline(always)]
+    fn is_settled(&self, epsilon: f32) -> bool {
+        self.component.is_settled(epsilon) && self.inner.is_settled(epsilon)
+    }
+}
+
+impl<'a, S, F, I> Scaffold for CompositionBefore<'a, S, F, I>
+where S: Stage,
+    F: FnOnce(S::Attribute) -> I::Output,
+    I: Scaffold + 'a {
+    type Output = I::Output;
+    type Raw = (I::Raw, S::Attribute);
+    #[inline(always)]
+    fn drive(self, time: f32) -> I::Output {
+        let inner = self.inner.drive(time);
+        let attrib = self.component.drive(time);
+        compose(inner, (self.f)(attrib))
+    }
+    #[inline(always)]
+    fn drive_raw(self, time: f32) -> Self::Raw {
+        let inner = self.inner.drive_raw(time);
+        (inner, self.component.drive(time))
+    }
+    #[inline(always)]
+    fn drive_collecting<E: Extend<DrivenValue>>(self, time: f32, values: &mut E) -> I::Output {
+        let inner = self.inner.drive_collecting(time, values);
+        let attrib = self.component.drive(time);
+        values.extend(core::iter::once(S::driven_value(attrib)));
+        compose(inner, (self.f)(attrib))
+    }
+    #[inline(always)]
+    fn at_fraction(self, t: f32) -> I::Output {
+        let inner = self.inner.at_fraction(t);
+        compose(inner, (self.f)(self.component.at_fraction(t)))
+    }
+    #[inline(always)]
+    fn peek(self, time: f32) -> I::Output {
+        let inner = self.inner.peek(time);
+        compose(inner, (self.f)(self.component.peek(time)))
+    }
+    #[inline(always)]
+    fn drive_fixed(self, time: f32, step: f32) -> I::Output {
+        let inner = self.inner.drive_fixed(time, step);
+        compose(inner, (self.f)(self.component.drive_fixed(time, step)))
+    }
+    #[inline(always)]
+    fn is_settled(&self, epsilon: f32) -> bool {
+        self.component.is_settled(epsilon) && self.inner.is_settled(epsilon)
+    }
+}
+
+impl<I: Scaffold<Output = Mat4>> AboutPivot<I> {
+    fn sandwich(pivot: Vec3, local: Mat4) -> Mat4 {
+        compose(Mat4::from_translation(pivot), compose(local, Mat4::from_translation(-pivot)))
+    }
+}
+
+impl<I: Scaffold<Output = Mat4>> Scaffold for AboutPivot<I> {
+    type Output = Mat4;
+    type Raw = I::Raw;
+    #[inline(always)]
+    fn drive(self, time: f32) -> Mat4 {
+        Self::sandwich(self.pivot, self.inner.drive(time))
+    }
+    #[inline(always)]
+    fn drive_raw(self, time: f32) -> I::Raw {
+        self.inner.drive_raw(time)
+    }
+    #[inline(always)]
+    fn drive_collecting<E: Extend<DrivenValue>>(self, time: f32, values: &mut E) -> Mat4 {
+        Self::sandwich(self.pivot, self.inner.drive_collecting(time, values))
+    }
+    #[inline(always)]
+    fn at_fraction(self, t: f32) -> Mat4 {
+        Self::sandwich(self.pivot, self.inner.at_fraction(t))
+    }
+    #[inline(always)]
+    fn peek(self, time: f32) -> Mat4 {
+        Self::sandwich(self.pivot, self.inner.peek(time))
+    }
+    #[inline(always)]
+    fn drive_fixed(self, time: f32, step: f32) -> Mat4 {
+        Self::sandwich(self.pivot, self.inner.drive_fixed(time, step))
+    }
+    #[inline(always)]
+    fn is_settled(&self, epsilon: f32) -> bool {
+        self.inner.is_settled(epsilon)
+    }
+}
+
+impl<I: Scaffold> Scaffold for InParent<I> {
+    type Output = I::Output;
+    type Raw = I::Raw;
+    #[inline(always)]
+    fn drive(self, time: f32) -> I::Output {
+        compose(self.parent_world, self.inner.drive(time))
+    }
+    #[inline(always)]
+    fn drive_raw(self, time: f32) -> I::Raw {
+        self.inner.drive_raw(time)
+    }
+    #[inline(always)]
+    fn drive_collecting<E: Extend<DrivenValue>>(self, time: f32, values: &mut E) -> I::Output {
+        compose(self.parent_world, self.inner.drive_collecting(time, values))
+    }
+    #[inline(always)]
+    fn at_fraction(self, t: f32) -> I::Output {
+        compose(self.parent_world, self.inner.at_fraction(t))
+    }
+    #[inline(always)]
+    fn peek(self, time: f32) -> I::Output {
+        compose(self.parent_world, self.inner.peek(time))
+    }
+    #[inline(always)]
+    fn drive_fixed(self, time: f32, step: f32) -> I::Output {
+        compose(self.parent_world, self.inner.drive_fixed(time, step))
+    }
+    #[inline(always)]
+    fn is_settled(&self, epsilon: f32) -> bool {
+        self.inner.is_settled(epsilon)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use glam::Affine2;
+    use super::*;
+
+    #[test]
+    #[allow(clippy::redundant_closure)]
+    fn this_works() {
+        let mut zoom = TransformComponent::new_zoom(2.0);
+        let mut rotate = TransformComponent::new_rotate(Quat::IDENTITY);
+        let mut translate = TransformComponent::new_translate(Vec3::ONE);
+        let mut angle = TransformComponent::new_angle(0.4);
+
+        zoom.target = 4.0;
+        rotate.target *= Quat::from_rotation_x(0.4);
+        translate.target += Vec3::ONE;
+        angle.target /= 3.0;
+
+        let delta_time = 0.03;
+
+        let transform_matrix = zoom.begin(|zoom| Mat4::from_translation(Vec3::splat(zoom)))
+            .and_then(&mut rotate, |quat| Mat4::from_quat(quat))
+            .and_then(&mut translate, |by| Mat4::from_translation(by))
+            .and_then(&mut angle, |angle| Mat4::from_rotation_y(angle))
+            .drive(delta_time);
 
         let inv = transform_matrix.inverse();
         assert!(inv.is_finite());
     }
+
+    #[test]
+    fn prime_snaps_first_drive() {
+        let mut translate = TransformComponent::new_translate(Vec3::ZERO);
+        translate.prime();
+        translate.target = Vec3::new(4.0, -2.0, 1.0);
+
+        assert_eq!(translate.drive(0.016), translate.target);
+        assert_eq!(translate.current, translate.target);
+
+        let previous = translate.current;
+        translate.target += Vec3::X;
+        let driven = translate.drive(0.016);
+        assert!(driven.x > previous.x && driven.x < translate.target.x);
+        assert_eq!((driven.y, driven.z), (previous.y, previous.z));
+    }
+
+    #[test]
+    fn grid_steps_snap_onto_cells() {
+        let cell_size = 2.0;
+        let pixel_size = 1.0 / 64.0;
+        let mut position = TransformComponent::new_grid(IVec3::ZERO, cell_size);
+
+        for cell in [IVec3::X, IVec3::new(2, 0, 0), IVec3::new(2, 1, 0), IVec3::new(1, 1, -1)] {
+            position.set_grid_target(cell, cell_size);
+            assert_eq!(position.target, cell.as_vec3() * cell_size);
+
+            let start = position.current;
+            let first = position.drive_snapped(0.016, pixel_size);
+            assert_ne!(first, start);
+            assert_ne!(first, position.target);
+
+            for _ in 0..120 {
+                position.drive_snapped(0.016, pixel_size);
+            }
+            assert_eq!(position.current, cell.as_vec3() * cell_size);
+        }
+    }
+
+    #[test]
+    fn const_construction() {
+        const PRESETS: [TransformComponent<Translate<Vec3>>; 2] = [
+            TransformComponent::new_translate(Vec3::new(0.0, 2.0, 5.0)),
+            TransformComponent::new(0.2, Vec3::ZERO),
+        ];
+        static LOOKING: TransformComponent<Rotate> = TransformComponent::new_rotate(Quat::IDENTITY);
+        const ZOOM: TransformComponent<Translate<f32>> = TransformComponent::new_zoom(1.5);
+
+        assert_eq!(PRESETS[0].current, Vec3::new(0.0, 2.0, 5.0));
+        assert_eq!(PRESETS[1].retention, 0.2);
+        assert_eq!(LOOKING.target, Quat::IDENTITY);
+
+        let mut zoom = ZOOM;
+        zoom.target = 3.0;
+        assert!(zoom.drive(0.016) > 1.5);
+    }
+
+    #[test]
+    fn target_changed_tracks_drives() {
+        let mut rotate = TransformComponent::new_rotate(Quat::IDENTITY);
+        assert!(!rotate.target_changed());
+
+        rotate.target = Quat::from_rotation_y(1.0);
+        assert!(rotate.target_changed());
+
+        rotate.drive(0.016);
+        assert!(!rotate.target_changed());
+
+        rotate.target = Quat::from_rotation_y(1.0);
+        rotate.drive(0.016);
+        assert!(!rotate.target_changed());
+    }
+
+    #[test]
+    fn far_hemisphere_target_is_unchanged_when_reassigned() {
+        let mut rotate = TransformComponent::new_rotate(Quat::IDENTITY);
+        let goal = -Quat::from_rotation_y(0.5);
+
+        for frame in 0..3 {
+            rotate.target = goal;
+            assert_eq!(rotate.target_changed(), frame == 0);
+            rotate.drive(0.016);
+            assert!(!rotate.target_changed());
+        }
+    }
+
+    #[test]
+    fn rotate_takes_short_arc_from_far_hemisphere() {
+        let mut rotate = TransformComponent::new_rotate(Quat::IDENTITY);
+        let goal = Quat::from_rotation_y(0.5);
+        rotate.target = -goal;
+
+        let driven = rotate.drive(0.016);
+        assert!(rotate.current.dot(rotate.target) >= 0.0);
+        assert!(rotate.target.abs_diff_eq(goal, 1e-6));
+
+        let (axis, angle) = driven.to_axis_angle();
+        assert!(angle > 0.0 && angle < 0.5);
+        assert!(axis.abs_diff_eq(Vec3::Y, 1e-4));
+        assert!(driven.angle_between(goal) < 0.5);
+    }
+
+    #[test]
+    fn compose_matches_and_then() {
+        let mut zoom = TransformComponent::new_zoom(2.0);
+        let mut translate = TransformComponent::new_translate(Vec3::new(1.0, 0.0, -3.0));
+
+        let chained = zoom.begin(|zoom| Mat4::from_scale(Vec3::splat(zoom)))
+            .and_then(&mut translate, Mat4::from_translation)
+            .drive(0.016);
+        let composed = compose(
+            Mat4::from_translation(translate.current),
+            Mat4::from_scale(Vec3::splat(zoom.current)),
+        );
+
+        assert_eq!(chained, composed);
+    }
+
+    #[test]
+    fn rotate_target_stays_normalized() {
+        let mut rotate = TransformComponent::new_rotate(Quat::IDENTITY);
+        for _ in 0..10_000 {
+            rotate.target *= Quat::from_rotation_x(0.3);
+        }
+        rotate.target = rotate.target * 1.01;
+
+        rotate.drive(0.016);
+        assert!((rotate.target.length() - 1.0).abs() < 1e-5);
+        assert!(rotate.current.is_normalized());
+    }
+
+    #[test]
+    fn tighter_retention_accumulates_less_error() {
+        let mut tight = TransformComponent::<Translate<Vec3>>::new(0.001, Vec3::ZERO);
+        let mut loose = TransformComponent::<Translate<Vec3>>::new(0.1, Vec3::ZERO);
+        tight.track_error();
+        loose.track_error();
+
+        for frame in 0..120 {
+            let target = Vec3::new((frame as f32 * 0.1).sin(), 0.0, frame as f32 * 0.05);
+            tight.target = target;
+            loose.target = target;
+            tight.drive(0.016);
+            loose.drive(0.016);
+        }
+
+        assert!(tight.integrated_error() > 0.0);
+        assert!(tight.integrated_error() < loose.integrated_error());
+
+        loose.reset_integrated_error();
+        assert_eq!(loose.integrated_error(), 0.0);
+        loose.target += Vec3::X;
+        loose.drive(0.016);
+        assert!(loose.integrated_error() > 0.0);
+
+        let mut untracked = TransformComponent::new_translate(Vec3::ZERO);
+        untracked.target = Vec3::X;
+        untracked.drive(0.016);
+        assert_eq!(untracked.integrated_error(), 0.0);
+    }
+
+    #[test]
+    fn drive_delta_reports_step() {
+        let mut translate = TransformComponent::new_translate(Vec3::new(1.0, 2.0, 3.0));
+        translate.target = Vec3::new(-4.0, 0.5, 10.0);
+
+        for _ in 0..10 {
+            let previous = translate.current;
+            let delta = translate.drive_delta(0.016);
+            assert!((previous + delta).abs_diff_eq(translate.current, 1e-6));
+            assert_ne!(delta, Vec3::ZERO);
+        }
+
+        let mut rotate = TransformComponent::new_rotate(Quat::from_rotation_x(0.2));
+        rotate.target = Quat::from_rotation_y(1.0);
+        let previous = rotate.current;
+        let delta = rotate.drive_delta(0.016);
+        assert!((delta * previous).abs_diff_eq(rotate.current, 1e-6));
+    }
+
+    #[test]
+    fn with_state_drives_predictably() {
+        let mut translate = TransformComponent::<Translate<Vec3>>::with_state(0.25, Vec3::splat(5.0), Vec3::splat(10.0));
+        assert_eq!(translate.current, Vec3::splat(5.0));
+        assert_eq!(translate.target, Vec3::splat(10.0));
+        assert!(!translate.target_changed());
+
+        // After half a second with a retention of 0.25, half of the
+        // remaining distance is kept.
+        let driven = translate.drive(0.5);
+        assert!(driven.abs_diff_eq(Vec3::splat(7.5), 1e-5));
+    }
+
+    #[test]
+    fn identities() {
+        assert_eq!(Translate::<Vec3>::identity(), Vec3::ZERO);
+        assert_eq!(Translate::<f32>::identity(), 0.0);
+        assert_eq!(Rotate::identity(), Quat::IDENTITY);
+        assert_eq!(Scale::<f32>::identity(), 1.0);
+        assert_eq!(Scale::<Vec3>::identity(), Vec3::ONE);
+
+        let mut rotate = TransformComponent::new_rotate(Quat::from_rotation_z(1.0));
+        rotate.reset_to_identity();
+        assert_eq!(rotate.current, Quat::IDENTITY);
+        assert_eq!(rotate.target, Quat::IDENTITY);
+    }
+
+    #[test]
+    fn weighted_stages() {
+        let mut translate = TransformComponent::new_translate(Vec3::new(0.0, 1.0, 0.0));
+        let mut rotate = TransformComponent::new_rotate(Quat::from_rotation_y(1.2));
+
+        let none = translate.begin(Mat4::from_translation)
+            .and_then_weighted(&mut rotate, 0.0, Mat4::from_quat)
+            .drive(0.016);
+        assert!(none.abs_diff_eq(Mat4::from_translation(translate.current), 1e-6));
+
+        let full = translate.begin(Mat4::from_translation)
+            .and_then_weighted(&mut rotate, 1.0, Mat4::from_quat)
+            .drive(0.016);
+        let expected = Mat4::from_quat(rotate.current) * Mat4::from_translation(translate.current);
+        assert!(full.abs_diff_eq(expected, 1e-6));
+
+        let half = rotate.begin(Mat4::from_quat)
+            .and_then_weighted(&mut translate, 0.5, Mat4::from_translation)
+            .drive(0.016);
+        let expected = Mat4::from_translation(translate.current * 0.5) * Mat4::from_quat(rotate.current);
+        assert!(half.abs_diff_eq(expected, 1e-6));
+    }
+
+    #[test]
+    fn has_moved_after_first_change() {
+        let mut translate = TransformComponent::new_translate(Vec3::ZERO);
+        assert!(!translate.has_moved());
+
+        translate.drive(0.016);
+        assert!(!translate.has_moved());
+
+        translate.target = Vec3::Y;
+        translate.drive(0.016);
+        assert!(translate.has_moved());
+
+        translate.hard_set(Vec3::ZERO);
+        translate.drive(0.016);
+        assert!(translate.has_moved());
+    }
+
+    #[test]
+    fn retarget_by_arrives_on_time() {
+        let mut translate = TransformComponent::new_translate(Vec3::ZERO);
+        translate.retarget_by(Vec3::new(3.0, 4.0, 0.0), 0.5, 0.01);
+
+        let delta_time = 0.5 / 30.0;
+        for _ in 0..29 {
+            translate.drive(delta_time);
+        }
+        assert!(translate.current.distance(translate.target) > 0.01);
+
+        translate.drive(delta_time);
+        assert!((translate.current.distance(translate.target) - 0.01).abs() < 1e-4);
+
+        let retention = translate.retention;
+        translate.retarget_by(translate.target, 1.0, 0.1);
+        assert_eq!(translate.retention, retention);
+
+        for epsilon in [0.0, -0.1, f32::NAN] {
+            translate.retarget_by(Vec3::ZERO, 1.0, epsilon);
+            assert_eq!(translate.current, Vec3::ZERO);
+            assert_eq!(translate.retention, retention);
+            translate.hard_set(Vec3::X);
+        }
+    }
+
+    #[test]
+    fn formatting() {
+        let translate = TransformComponent::<Translate<Vec3>>::with_state(0.25, Vec3::ZERO, Vec3::new(3.0, 4.0, 0.0));
+
+        let debug = format!("{translate:?}");
+        assert!(debug.contains("retention: 0.25"));
+        assert!(debug.contains(&format!("current: {:?}", Vec3::ZERO)));
+        assert!(debug.contains("distance: 5.0"));
+        assert!(!debug.contains("PhantomData"));
+
+        let display = translate.to_string();
+        assert_eq!(display, format!("{} -> {} (retention 0.25, distance 5)", Vec3::ZERO, translate.target));
+    }
+
+    #[test]
+    fn accumulated_drive_batches_steps() {
+        let mut batched = TransformComponent::new_translate(Vec3::ZERO);
+        let mut reference = TransformComponent::new_translate(Vec3::ZERO);
+        batched.target = Vec3::new(1.0, 2.0, 3.0);
+        reference.target = batched.target;
+
+        assert_eq!(batched.drive_accumulated(0.01, 0.05), None);
+        assert_eq!(batched.drive_accumulated(0.015, 0.05), None);
+        assert_eq!(batched.drive_accumulated(0.02, 0.05), None);
+        assert_eq!(batched.current, Vec3::ZERO);
+
+        let driven = batched.drive_accumulated(0.01, 0.05).unwrap();
+        assert!(driven.abs_diff_eq(reference.drive(0.055), 1e-6));
+        assert_eq!(batched.drive_accumulated(0.01, 0.05), None);
+    }
+
+    #[test]
+    fn cascaded_retentions() {
+        let (a, b) = (0.02, 0.1);
+        let combined = combined_retention(a, b);
+        assert!(combined > a.max(b) && combined < 1.0);
+        assert!((combined_retention(split_retention(0.05), split_retention(0.05)) - 0.05).abs() < 1e-6);
+
+        let mut first = TransformComponent::<Translate<f32>>::new(a, 0.0);
+        let mut second = TransformComponent::<Translate<f32>>::new(b, 0.0);
+        let mut single = TransformComponent::<Translate<f32>>::new(combined, 0.0);
+        first.target = 1.0;
+        single.target = 1.0;
+        single.track_error();
+
+        let delta_time = 0.001;
+        let mut cascade_error = 0.0;
+        let mut cascade_settle = None;
+        let mut single_settle = None;
+        for step in 1..=10_000 {
+            second.target = first.drive(delta_time);
+            let cascade = second.drive(delta_time);
+            cascade_error += (1.0 - cascade) * delta_time;
+            let single = single.drive(delta_time);
+
+            let time = step as f32 * delta_time;
+            if cascade > 0.95 && cascade_settle.is_none() {
+                cascade_settle = Some(time);
+            }
+            if single > 0.95 && single_settle.is_none() {
+                single_settle = Some(time);
+            }
+        }
+
+        assert!((cascade_error - single.integrated_error()).abs() < 0.01);
+        let (cascade_settle, single_settle) = (cascade_settle.unwrap(), single_settle.unwrap());
+        assert!((cascade_settle - single_settle).abs() < single_settle * 0.25);
+    }
+
+    #[test]
+    fn dead_zone_ignores_noise() {
+        let mut translate = TransformComponent::new_translate(Vec3::ZERO);
+        translate.dead_zone = Some(DeadZone::with_hysteresis(0.01, 0.05));
+
+        for frame in 0..100 {
+            let noise = (frame as f32 * 1.7).sin() * 0.04;
+            translate.target = Vec3::new(noise, -noise, 0.0) * 0.5;
+            translate.drive(0.016);
+            assert_eq!(translate.current, Vec3::ZERO);
+        }
+
+        translate.target = Vec3::X;
+        let moved = translate.drive(0.016);
+        assert!(moved.x > 0.0);
+
+        // Once moving, it keeps going until it's well inside the dead zone.
+        for _ in 0..100 {
+            translate.drive(0.016);
+        }
+        assert!(translate.current.distance(Vec3::X) < 0.01);
+        let rest = translate.current;
+
+        translate.target = Vec3::X + Vec3::Y * 0.04;
+        translate.drive(0.016);
+        assert_eq!(translate.current, rest);
+    }
+
+    #[test]
+    fn cutoff_attenuates_by_3db() {
+        let cutoff = 2.0;
+        let mut filter = TransformComponent::<Translate<f32>>::from_cutoff(cutoff, 0.0);
+        assert!((filter.cutoff_hz() - cutoff).abs() < 1e-4);
+
+        let delta_time = 1e-4;
+        let mut peak = 0.0f32;
+        for step in 0..50_000 {
+            let time = step as f32 * delta_time;
+            filter.target = (TAU * cutoff * time).sin();
+            let filtered = filter.drive(delta_time);
+            if time > 4.0 {
+                peak = peak.max(filtered.abs());
+            }
+        }
+
+        assert!((peak - std::f32::consts::FRAC_1_SQRT_2).abs() < 0.01);
+    }
+
+    #[test]
+    fn snapshot_and_restore() {
+        let mut translate = TransformComponent::new_translate(Vec3::ZERO);
+        translate.dead_zone = Some(DeadZone::new(0.001));
+        translate.track_error();
+        translate.target = Vec3::new(2.0, 0.0, -1.0);
+        for _ in 0..5 {
+            translate.drive(0.016);
+        }
+        assert_eq!(translate.drive_accumulated(0.01, 0.05), None);
+
+        let snapshot = translate.snapshot();
+        let expected = translate.drive(0.016);
+        let expected_error = translate.integrated_error();
+        let expected_accumulated = translate.drive_accumulated(0.04, 0.05);
+
+        translate.target = Vec3::ONE;
+        for _ in 0..5 {
+            translate.drive(0.016);
+        }
+
+        translate.restore(snapshot);
+        assert_eq!(translate.target, Vec3::new(2.0, 0.0, -1.0));
+        assert_eq!(translate.drive(0.016), expected);
+        assert_eq!(translate.integrated_error(), expected_error);
+        assert_eq!(translate.drive_accumulated(0.04, 0.05), expected_accumulated);
+    }
+
+    #[test]
+    fn direction_sweeps_short_arc() {
+        let mut looking = TransformComponent::<Direction>::new(0.05, Vec3::X);
+        looking.target = Vec3::new(-1.0, 1.0, 0.0).normalize();
+
+        let mut previous_angle = Direction::distance(looking.current, looking.target);
+        for _ in 0..30 {
+            let driven = looking.drive(0.05);
+            assert!(driven.is_normalized());
+            assert!(driven.z.abs() < 1e-5);
+            assert!(driven.y >= 0.0);
+
+            let angle = Direction::distance(driven, looking.target);
+            assert!(angle < previous_angle);
+            previous_angle = angle;
+        }
+
+        let mut opposite = TransformComponent::<Direction>::new(0.05, Vec3::X);
+        opposite.target = Vec3::NEG_X;
+        let driven = opposite.drive(0.05);
+        assert!(driven.is_normalized());
+        assert!(Direction::distance(driven, Vec3::NEG_X) < std::f32::consts::PI);
+    }
+
+    #[test]
+    fn direction_recovers_from_zero() {
+        let mut looking = TransformComponent::<Direction>::new(0.05, Vec3::ZERO);
+        looking.target = Vec3::X;
+
+        let driven = looking.drive(0.05);
+        assert!(driven.is_finite() && driven.is_normalized());
+        for _ in 0..300 {
+            looking.drive(0.05);
+        }
+        assert!(looking.is_settled(1e-3));
+
+        looking.target = Vec3::ZERO;
+        assert!(looking.drive(0.05).is_normalized());
+    }
+
+    #[test]
+    fn step_capped_after_hitch() {
+        let mut translate = TransformComponent::new_translate(Vec3::ZERO);
+        translate.target = Vec3::new(30.0, 40.0, 0.0);
+
+        let capped = translate.drive_step_capped(5.0, 1.0);
+        assert!((capped.length() - 1.0).abs() < 1e-5);
+        assert!(capped.abs_diff_eq(Vec3::new(0.6, 0.8, 0.0), 1e-5));
+
+        let mut near = TransformComponent::new_translate(Vec3::ZERO);
+        near.target = Vec3::X * 0.5;
+        assert_eq!(near.drive_step_capped(5.0, 1.0), near.target);
+
+        let mut rotate = TransformComponent::new_rotate(Quat::IDENTITY);
+        rotate.target = Quat::from_rotation_y(2.0);
+        let turned = rotate.drive_step_capped(5.0, 0.25);
+        assert!((turned.angle_between(Quat::IDENTITY) - 0.25).abs() < 1e-4);
+    }
+
+    #[test]
+    fn normal_matrix() {
+        let mut scale = TransformComponent::new_zoom(Vec3::new(1.0, 4.0, 1.0));
+        let mut rotate = TransformComponent::new_rotate(Quat::from_rotation_z(0.7));
+
+        let (matrix, normal) = scale.begin(Mat4::from_scale)
+            .and_then(&mut rotate, Mat4::from_quat)
+            .drive_with_normal(0.016);
+        assert!(normal.abs_diff_eq(Mat3::from_mat4(matrix).inverse().transpose(), 1e-5));
+
+        // A surface tilted within the stretched plane keeps its normal
+        // perpendicular after the transformation.
+        let tangent = Vec3::new(1.0, 1.0, 0.0);
+        let surface_normal = Vec3::new(1.0, -1.0, 0.0);
+        let tangent = matrix.transform_vector3(tangent);
+        assert!(tangent.dot(normal * surface_normal).abs() < 1e-5);
+        assert!(tangent.dot(Mat3::from_mat4(matrix) * surface_normal).abs() > 0.1);
+
+        let mut uniform = TransformComponent::new_zoom(Vec3::splat(2.0));
+        let (matrix, normal) = uniform.begin(Mat4::from_scale)
+            .and_then(&mut rotate, Mat4::from_quat)
+            .drive_with_normal(0.016);
+        assert!(normal.abs_diff_eq(Mat3::from_mat4(matrix).inverse().transpose(), 1e-5));
+    }
+
+    #[test]
+    fn default_retention_constants() {
+        assert_eq!(TransformComponent::new_translate(Vec3::ZERO).retention, DEFAULT_TRANSLATE_RETENTION);
+        assert_eq!(TransformComponent::new_zoom(1.0).retention, DEFAULT_ZOOM_RETENTION);
+        assert_eq!(TransformComponent::new_angle(0.0).retention, DEFAULT_ANGLE_RETENTION);
+        assert_eq!(TransformComponent::new_rotate(Quat::IDENTITY).retention, DEFAULT_ROTATE_RETENTION);
+    }
+
+    #[test]
+    fn drive_toward_moving_target() {
+        let mut follow = TransformComponent::new_translate(Vec3::ZERO);
+        let mut leader = Vec3::new(5.0, 0.0, 0.0);
+
+        for _ in 0..200 {
+            leader += Vec3::Y * 0.001;
+            follow.drive_toward(leader, 0.016);
+        }
+
+        assert_eq!(follow.target, leader);
+        assert!(follow.current.distance(leader) < 0.02);
+    }
+
+    #[test]
+    fn rotate_recovers_from_degenerate_quaternions() {
+        let mut rotate = TransformComponent::new_rotate(Quat::from_rotation_x(0.5));
+        rotate.target = Quat::from_xyzw(0.0, 0.0, 0.0, 0.0);
+        let driven = rotate.drive(0.016);
+        assert!(driven.is_finite() && driven.is_normalized());
+        assert!(driven.abs_diff_eq(Quat::from_rotation_x(0.5), 1e-5));
+
+        rotate.hard_set(Quat::from_xyzw(f32::NAN, 0.0, 0.0, 1.0));
+        let driven = rotate.drive(0.016);
+        assert!(driven.is_finite() && driven.is_normalized());
+
+        rotate.current = Quat::from_xyzw(0.0, 0.0, 0.0, 0.0);
+        rotate.target = Quat::from_rotation_y(1.0);
+        let driven = rotate.drive(0.016);
+        assert!(driven.abs_diff_eq(rotate.target, 1e-5));
+    }
+
+    #[test]
+    fn chain_at_fraction() {
+        let mut zoom = TransformComponent::<Translate<f32>>::with_state(0.03, 1.0, 3.0);
+        let mut rotate = TransformComponent::<Rotate>::with_state(0.04, Quat::IDENTITY, Quat::from_rotation_y(1.0));
+        let mut translate = TransformComponent::<Translate<Vec3>>::with_state(0.01, Vec3::ZERO, Vec3::new(1.0, 2.0, 3.0));
+
+        let matrix_for = |zoom: f32, rotate: Quat, translate: Vec3| {
+            Mat4::from_translation(translate) * Mat4::from_quat(rotate) * Mat4::from_scale(Vec3::splat(zoom))
+        };
+
+        let start = zoom.begin(|zoom| Mat4::from_scale(Vec3::splat(zoom)))
+            .and_then(&mut rotate, Mat4::from_quat)
+            .and_then(&mut translate, Mat4::from_translation)
+            .at_fraction(0.0);
+        assert!(start.abs_diff_eq(matrix_for(1.0, Quat::IDENTITY, Vec3::ZERO), 1e-6));
+
+        let end = zoom.begin(|zoom| Mat4::from_scale(Vec3::splat(zoom)))
+            .and_then(&mut rotate, Mat4::from_quat)
+            .and_then(&mut translate, Mat4::from_translation)
+            .at_fraction(1.0);
+        assert!(end.abs_diff_eq(matrix_for(3.0, Quat::from_rotation_y(1.0), Vec3::new(1.0, 2.0, 3.0)), 1e-5));
+
+        assert_eq!(zoom.current, 1.0);
+        assert_eq!(rotate.current, Quat::IDENTITY);
+        assert_eq!(translate.current, Vec3::ZERO);
+        assert_eq!(zoom.at_fraction(0.5), 2.0);
+    }
+
+    #[test]
+    fn translate_never_overshoots() {
+        assert_eq!(Translate::drive(4.0, 2.0, 1.5), 4.0);
+        assert_eq!(Translate::drive(4.0, 2.0, -0.5), 2.0);
+        assert_eq!(Translate::drive(Vec3::X, Vec3::ZERO, 3.0), Vec3::X);
+
+        let mut translate = TransformComponent::new_translate(Vec3::ZERO);
+        translate.target = Vec3::new(1.0, -2.0, 0.5);
+        translate.drive(1e6);
+        assert_eq!(translate.current, translate.target);
+
+        let mut weighted = TransformComponent::new_translate(Vec3::X);
+        let matrix = weighted.begin(Mat4::from_translation)
+            .and_then_weighted(&mut TransformComponent::new_translate(Vec3::Y), 2.0, Mat4::from_translation)
+            .drive(0.016);
+        assert_eq!(matrix, Mat4::from_translation(Vec3::new(1.0, 1.0, 0.0)));
+    }
+
+    #[test]
+    fn validation() {
+        let mut translate = TransformComponent::new_translate(Vec3::ZERO);
+        assert_eq!(translate.validate(), Ok(()));
+
+        for retention in [0.0, 1.0, 1.5, -0.2, f32::NAN] {
+            translate.retention = retention;
+            assert!(matches!(translate.validate(), Err(StabilityError::RetentionOutOfRange(_))));
+        }
+        translate.retention = DEFAULT_TRANSLATE_RETENTION;
+
+        translate.current = Vec3::new(f32::NAN, 0.0, 0.0);
+        assert_eq!(translate.validate(), Err(StabilityError::NonFiniteCurrent));
+        translate.current = Vec3::ZERO;
+
+        translate.target = Vec3::new(0.0, f32::INFINITY, 0.0);
+        assert_eq!(translate.validate(), Err(StabilityError::NonFiniteTarget));
+
+        let mut rotate = TransformComponent::new_rotate(Quat::IDENTITY);
+        rotate.target = Quat::from_xyzw(f32::NAN, 0.0, 0.0, 1.0);
+        assert_eq!(rotate.validate(), Err(StabilityError::NonFiniteTarget));
+
+        let zoom = TransformComponent::<Scale>::new(0.03, 0.0);
+        assert_eq!(zoom.validate(), Err(StabilityError::NonFiniteCurrent));
+    }
+
+    #[test]
+    fn quaternion_drift() {
+        let mut rotate = TransformComponent::new_rotate(Quat::from_rotation_x(0.5));
+        assert!(rotate.quat_error() < 1e-6);
+
+        rotate.current = rotate.current * 1.01;
+        assert!((rotate.quat_error() - 0.0201).abs() < 1e-4);
+
+        assert!(!rotate.renormalize(0.05));
+        assert!(rotate.renormalize(1e-4));
+        assert!(rotate.quat_error() < 1e-6);
+        assert!(rotate.current.abs_diff_eq(Quat::from_rotation_x(0.5), 1e-6));
+
+        rotate.current = rotate.current * 1.01;
+        assert!(rotate.renormalize(DEFAULT_RENORM_EPSILON));
+        assert!(!rotate.renormalize(DEFAULT_RENORM_EPSILON));
+
+        rotate.current = rotate.current * 0.9;
+        rotate.drive(0.016);
+        assert!(rotate.quat_error() < 1e-6);
+    }
+
+    #[test]
+    fn drive_frames_converges_monotonically() {
+        let mut position = TransformComponent::new_translate(0.0f32);
+        position.target = 1.0;
+
+        let frames: Vec<f32> = position.drive_frames(1.0, 0.1).collect();
+        assert_eq!(frames.len(), 10);
+        assert!(frames.windows(2).all(|pair| pair[0] < pair[1] && pair[1] <= 1.0));
+        assert_eq!(*frames.last().unwrap(), position.current);
+
+        let mut reference = TransformComponent::new_translate(0.0f32);
+        reference.target = 1.0;
+        assert!((reference.drive(1.0) - position.current).abs() < 1e-5);
+    }
+
+    #[test]
+    fn drive_frames_counts_exactly() {
+        let mut position = TransformComponent::new_translate(0.0f32);
+        assert_eq!(position.drive_frames(0.3, 0.1).count(), 3);
+        assert_eq!(position.drive_frames(1.0, 1.0 / 60.0).count(), 60);
+        assert_eq!(position.drive_frames(0.25, 0.1).count(), 3);
+        assert_eq!(position.drive_frames(0.0, 0.1).count(), 0);
+        assert_eq!(position.drive_frames(1.0, 0.0).count(), 0);
+
+        // A large ratio is only rounded, and gains no extra frame.
+        assert_eq!(position.drive_frames(1000.0, 0.001).count(), 1_000_000);
+
+        // A real remainder far below a step still gets its own frame.
+        assert_eq!(position.drive_frames(0.1 + 1e-6, 0.1).count(), 2);
+    }
+
+    #[test]
+    fn euler_axes_settle_independently() {
+        let mut angles = TransformComponent::<RotateEuler>::new(0.04, Vec3::ZERO);
+        angles.target = Vec3::new(3.0, -0.5, 0.0);
+
+        angles.drive(0.1);
+        assert_eq!(angles.current.z, 0.0);
+        for _ in 0..100 {
+            angles.drive(0.1);
+        }
+        assert!((angles.current.x - 3.0).abs() < 1e-3);
+        assert!((angles.current.y + 0.5).abs() < 1e-3);
+
+        // Yaw takes the short way across the seam.
+        angles.target.x = -3.0;
+        angles.drive(0.1);
+        assert!(angles.current.x > 3.0 || angles.current.x < -3.0);
+
+        let quat = RotateEuler::to_quat(Vec3::new(0.3, 0.2, 0.1));
+        assert!(RotateEuler::from_quat(quat).abs_diff_eq(Vec3::new(0.3, 0.2, 0.1), 1e-5));
+    }
+
+    #[test]
+    fn adaptive_retention_relaxes_with_error() {
+        let mut position = TransformComponent::new_translate(Vec3::ZERO);
+        position.target = Vec3::X * 10.0;
+
+        position.drive_adaptive(0.016, 0.001, 0.1, 5.0);
+        assert_eq!(position.retention, 0.001);
+
+        let mut previous = position.retention;
+        for _ in 0..300 {
+            position.drive_adaptive(0.016, 0.001, 0.1, 5.0);
+            assert!(position.retention >= previous);
+            previous = position.retention;
+        }
+        assert!(position.retention > 0.09);
+        assert!(position.current.distance(position.target) < 1e-2);
+    }
+
+    #[test]
+    fn in_parent_applies_local_first() {
+        let mut rotation = TransformComponent::new_rotate(Quat::from_rotation_z(std::f32::consts::FRAC_PI_2));
+        let parent_world = Mat4::from_translation(Vec3::new(5.0, 0.0, 0.0));
+
+        let world = rotation.begin(Mat4::from_quat)
+            .in_parent(parent_world)
+            .drive(0.016);
+
+        // The child's X axis is rotated onto Y, and then moved to the parent.
+        let point = world.transform_point3(Vec3::X);
+        assert!(point.abs_diff_eq(Vec3::new(5.0, 1.0, 0.0), 1e-5));
+    }
+
+    #[test]
+    fn drive_settling_fires_once_per_settle() {
+        let mut position = TransformComponent::new_translate(0.0f32);
+        assert!(!position.drive_settling(0.016, 1e-3).1);
+
+        let count_settles = |position: &mut TransformComponent<Translate<f32>>| {
+            (0..500).filter(|_| position.drive_settling(0.016, 1e-3).1).count()
+        };
+
+        position.target = 1.0;
+        assert_eq!(count_settles(&mut position), 1);
+        assert!((position.current - 1.0).abs() <= 1e-3);
+
+        position.target = -1.0;
+        assert_eq!(count_settles(&mut position), 1);
+    }
+
+    #[test]
+    fn f32_and_f64_scalars_converge() {
+        let mut single = TransformComponent::<Translate<Vec3>>::new(0.25, Vec3::ZERO);
+        let mut double = TransformComponent::<Translate<DVec3>>::new(0.25, DVec3::ZERO);
+        single.target = Vec3::X;
+        double.target = DVec3::X;
+
+        single.drive(0.5);
+        double.drive(0.5);
+        assert!((single.current.x - 0.5).abs() < 1e-6);
+        assert!((double.current.x - 0.5).abs() < 1e-12);
+
+        // Far from the origin, f64 still resolves tiny steps.
+        let mut far = TransformComponent::<Translate<f64>>::new(0.01, 1e9);
+        far.target = 1e9 + 1.0;
+        for _ in 0..200 {
+            far.drive(0.016);
+        }
+        assert!((far.current - far.target).abs() < 1e-3);
+        assert!(far.current > 1e9);
+    }
+
+    #[test]
+    fn constrained_axis_tracks_only_yaw() {
+        let mut turret = TransformComponent::new_rotate(Quat::IDENTITY);
+        let aim = Quat::from_rotation_y(1.2) * Quat::from_rotation_x(0.5);
+        turret.target = aim;
+
+        for _ in 0..200 {
+            turret.drive_constrained_axis(Vec3::Y, 0.05);
+        }
+        assert!(turret.current.angle_between(Quat::from_rotation_y(1.2)) < 1e-3);
+
+        let (swing, twist) = Rotate::swing_twist(aim, Vec3::Y);
+        assert!((swing * twist).angle_between(aim) < 1e-5);
+        assert!((swing * Vec3::Y).dot(Vec3::Y) < 1.0 - 1e-3);
+        let forward = turret.current * Vec3::NEG_Z;
+        assert!(forward.y.abs() < 1e-4);
+    }
+
+    #[test]
+    fn component_from_tuple() {
+        let position: TransformComponent<Translate<Vec3>> = (0.2, Vec3::X).into();
+        assert_eq!(position.retention, 0.2);
+        assert_eq!(position.current, Vec3::X);
+        assert_eq!(position.target, Vec3::X);
+    }
+
+    #[test]
+    fn pixel_snapped_output_keeps_smooth_state() {
+        let mut sprite = TransformComponent::<Translate<Vec2>>::new(0.5, Vec2::ZERO);
+        sprite.target = Vec2::new(10.0, -3.0);
+
+        let mut previous = sprite.current;
+        for _ in 0..20 {
+            let snapped = sprite.drive_pixel_snapped(0.016, 16.0);
+            assert_eq!(snapped * 16.0, (snapped * 16.0).round());
+            assert!(sprite.current.x > previous.x);
+            previous = sprite.current;
+        }
+        assert_ne!(sprite.current * 16.0, (sprite.current * 16.0).round());
+    }
+
+    #[test]
+    fn about_pivot_rotates_around_point() {
+        let mut spin = TransformComponent::new_rotate(Quat::from_rotation_z(std::f32::consts::FRAC_PI_2));
+        let pivot = Vec3::new(2.0, 1.0, 0.0);
+
+        let transform = spin.begin(Mat4::from_quat)
+            .about_pivot(pivot)
+            .drive(0.016);
+
+        // (3, 1) is one unit right of the pivot, and ends up one unit above it.
+        let point = transform.transform_point3(Vec3::new(3.0, 1.0, 0.0));
+        assert!(point.abs_diff_eq(Vec3::new(2.0, 2.0, 0.0), 1e-5));
+        assert!(transform.transform_point3(pivot).abs_diff_eq(pivot, 1e-5));
+    }
+
+    #[test]
+    fn input_speed_picks_retention() {
+        let map = |speed: f32| if speed > 100.0 { 0.001 } else { 0.2 };
+        let mut flick = TransformComponent::<Rotate2D>::new(0.1, 0.0);
+        let mut aim = TransformComponent::<Rotate2D>::new(0.1, 0.0);
+        flick.target = 1.0;
+        aim.target = 1.0;
+
+        flick.drive_input_adaptive(500.0, 0.016, map);
+        aim.drive_input_adaptive(5.0, 0.016, map);
+        assert_eq!(flick.retention, 0.001);
+        assert_eq!(aim.retention, 0.2);
+        assert!(flick.current > aim.current);
+    }
+
+    #[test]
+    fn drive_verbose_collects_each_stage() {
+        let mut zoom = TransformComponent::new_zoom(1.0);
+        let mut rotate = TransformComponent::new_rotate(Quat::IDENTITY);
+        let mut translate = TransformComponent::new_translate(Vec3::ZERO);
+        zoom.target = 2.0;
+        rotate.target = Quat::from_rotation_y(1.0);
+        translate.target = Vec3::X;
+
+        let (matrix, values): (_, Vec<_>) = zoom.begin(|zoom| Mat4::from_scale(Vec3::splat(zoom)))
+            .and_then(&mut rotate, Mat4::from_quat)
+            .and_then(&mut translate, Mat4::from_translation)
+            .in_parent(Mat4::IDENTITY)
+            .drive_verbose(0.016);
+
+        assert_eq!(values, [
+            DrivenValue::F32(zoom.current),
+            DrivenValue::Quat(rotate.current),
+            DrivenValue::Vec3(translate.current),
+        ]);
+        let expected = Mat4::from_translation(translate.current)
+            * Mat4::from_quat(rotate.current)
+            * Mat4::from_scale(Vec3::splat(zoom.current));
+        assert!(matrix.abs_diff_eq(expected, 1e-6));
+    }
+
+    #[test]
+    fn custom_translations_report_other() {
+        #[derive(Clone, Copy, Default, PartialEq, Debug)]
+        struct Meters(f32);
+
+        impl Add for Meters {
+            type Output = Meters;
+            fn add(self, other: Meters) -> Meters {
+                Meters(self.0 + other.0)
+            }
+        }
+
+        impl Sub for Meters {
+            type Output = Meters;
+            fn sub(self, other: Meters) -> Meters {
+                Meters(self.0 - other.0)
+            }
+        }
+
+        impl Mul<f32> for Meters {
+            type Output = Meters;
+            fn mul(self, percent: f32) -> Meters {
+                Meters(self.0 * percent)
+            }
+        }
+
+        impl Magnitude for Meters {
+            type Scalar = f32;
+            fn magnitude(self) -> f32 {
+                self.0.abs()
+            }
+
+            fn dot(self, other: Meters) -> f32 {
+                self.0 * other.0
+            }
+        }
+
+        let mut offset = TransformComponent::new_translate(Meters(0.0));
+        offset.target = Meters(1.0);
+        let (_, values): (_, Vec<_>) = offset.begin(|offset| Mat4::from_translation(Vec3::X * offset.0))
+            .drive_verbose(0.016);
+        assert_eq!(values, [DrivenValue::Other]);
+        assert!(offset.current.0 > 0.0);
+    }
+
+    #[test]
+    fn undo_restores_previous_current() {
+        let mut position = TransformComponent::new_translate(Vec3::new(0.3, 0.1, -0.7));
+        position.target = Vec3::new(5.0, 2.0, 1.0);
+        position.drive(0.016);
+
+        let before = position.current;
+        let driven = position.drive(0.016);
+        assert_ne!(driven, before);
+
+        position.undo_last_drive();
+        assert_eq!(position.current, before);
+        position.undo_last_drive();
+        assert_eq!(position.current, before);
+        assert_eq!(position.drive(0.016), driven);
+    }
+
+    #[test]
+    fn settled_components_and_chains() {
+        let mut position = TransformComponent::new_translate(Vec3::ZERO);
+        let mut rotation = TransformComponent::new_rotate(Quat::IDENTITY);
+        assert!(position.is_settled(1e-4));
+
+        rotation.target = Quat::from_rotation_y(0.5);
+        assert!(!rotation.is_settled(1e-4));
+        assert!(!position.begin(Mat4::from_translation)
+            .and_then(&mut rotation, Mat4::from_quat)
+            .is_settled(1e-4));
+
+        for _ in 0..500 {
+            rotation.drive(0.016);
+        }
+        assert!(rotation.is_settled(1e-4));
+        assert!(position.begin(Mat4::from_translation)
+            .and_then(&mut rotation, Mat4::from_quat)
+            .is_settled(1e-4));
+    }
+
+    #[test]
+    fn scale_is_geometric_and_clamps_domain() {
+        let mut zoom = TransformComponent::new_scale(1.0);
+        assert_eq!(zoom.retention, DEFAULT_ZOOM_RETENTION);
+        zoom.target = 100.0;
+
+        // Time after which half of the remaining log distance is covered.
+        let half = 0.5f32.ln() / zoom.retention.ln();
+        assert!((zoom.drive(half) - 10.0).abs() < 1e-3);
+
+        zoom.target = 0.0;
+        for _ in 0..10 {
+            let value = zoom.drive(0.1);
+            assert!(value.is_finite() && value > 0.0);
+        }
+
+        let mut scale = TransformComponent::new_scale(Vec3::new(-1.0, 0.0, 2.0));
+        scale.target = Vec3::ONE;
+        assert!(scale.drive(0.016).is_finite());
+    }
+
+    #[test]
+    fn wrapped_angle_takes_short_path() {
+        let mut heading = TransformComponent::new_angle_wrapped(0.1);
+        let mut unbounded = TransformComponent::new_angle(0.1);
+        assert_eq!(heading.retention, unbounded.retention);
+        heading.target = 6.2;
+        unbounded.target = 6.2;
+
+        let wrapped = heading.drive(0.1);
+        assert!(wrapped < 0.1 && wrapped > 0.1 - 0.19);
+        assert!(unbounded.drive(0.1) > 0.1);
+
+        for _ in 0..200 {
+            heading.drive(0.1);
+        }
+        assert!(Angle::distance(heading.current, 6.2) < 1e-4);
+        assert!(heading.current >= -PI && heading.current < PI);
+    }
+
+    #[test]
+    fn affine_output_matches_mat4() {
+        let mut translate = TransformComponent::new_translate(Vec3::ZERO);
+        let mut rotate = TransformComponent::new_rotate(Quat::IDENTITY);
+        translate.target = Vec3::new(1.0, 2.0, 3.0);
+        rotate.target = Quat::from_rotation_x(0.7);
+
+        let affine = translate.begin(glam::Affine3A::from_translation)
+            .and_then(&mut rotate, glam::Affine3A::from_quat)
+            .at_fraction(0.5);
+        let matrix = translate.begin(Mat4::from_translation)
+            .and_then(&mut rotate, Mat4::from_quat)
+            .at_fraction(0.5);
+        assert!(Mat4::from(affine).abs_diff_eq(matrix, 1e-6));
+
+        let planar: glam::Mat3 = rotate.begin(glam::Mat3::from_quat).drive(0.016);
+        assert!(planar.is_finite());
+    }
+
+    #[test]
+    fn peek_matches_drive_without_mutating() {
+        let mut translate = TransformComponent::new_translate(Vec3::ZERO);
+        let mut rotate = TransformComponent::new_rotate(Quat::IDENTITY);
+        translate.target = Vec3::new(3.0, 0.0, -1.0);
+        rotate.target = Quat::from_rotation_z(1.0);
+
+        let peeked = translate.peek(0.016);
+        assert_eq!(translate.current, Vec3::ZERO);
+
+        let predicted = translate.begin(Mat4::from_translation)
+            .and_then(&mut rotate, Mat4::from_quat)
+            .peek(0.016);
+        assert_eq!(rotate.current, Quat::IDENTITY);
+
+        let driven = translate.begin(Mat4::from_translation)
+            .and_then(&mut rotate, Mat4::from_quat)
+            .drive(0.016);
+        assert_eq!(predicted, driven);
+        assert_eq!(peeked, translate.current);
+    }
+
+    #[test]
+    fn half_life_and_time_constant() {
+        let mut half = TransformComponent::<Translate<f32>>::from_half_life(0.12, 0.0);
+        assert!((half.half_life() - 0.12).abs() < 1e-5);
+        half.target = 1.0;
+        assert!((half.drive(0.12) - 0.5).abs() < 1e-5);
+
+        let mut tau = TransformComponent::<Translate<f32>>::from_time_constant(0.3, 0.0);
+        tau.target = 1.0;
+        assert!((tau.drive(0.3) - (1.0 - (-1.0f32).exp())).abs() < 1e-5);
+    }
+
+    #[test]
+    fn f64_rotation_and_delta_time() {
+        let mut rotation = TransformComponent::new_rotate64(DQuat::IDENTITY);
+        rotation.target = DQuat::from_rotation_y(2.0);
+        for _ in 0..1000 {
+            rotation.drive_scalar(1.0 / 120.0);
+        }
+        assert!(rotation.current.angle_between(rotation.target) < 1e-9);
+
+        let mut position = TransformComponent::<Translate<DVec3>>::new(0.25, DVec3::splat(1e12));
+        position.target = DVec3::splat(1e12) + DVec3::X;
+        position.drive_scalar(0.5);
+        assert!((position.current.x - 1e12 - 0.5).abs() < 1e-3);
+        assert_eq!(position.current.y, 1e12);
+    }
+
+    #[test]
+    fn drive_with_default_curve_matches_drive() {
+        let mut a = TransformComponent::new_rotate(Quat::IDENTITY);
+        a.target = Quat::from_rotation_y(2.0);
+        let mut b = a;
+
+        for _ in 0..10 {
+            assert_eq!(a.drive(0.016), b.drive_with(0.016, exponential_percent));
+        }
+    }
+
+    #[test]
+    fn max_speed_caps_movement() {
+        let mut position = TransformComponent::new_translate(Vec3::ZERO);
+        position.max_speed = Some(2.0);
+        position.target = Vec3::X * 100.0;
+
+        let mut previous = position.current;
+        for _ in 0..10 {
+            let current = position.drive(0.1);
+            assert!((current - previous).length() <= 0.2 + 1e-5);
+            previous = current;
+        }
+        assert!((previous.x - 2.0).abs() < 1e-4);
+
+        let mut rotation = TransformComponent::new_rotate(Quat::IDENTITY);
+        rotation.max_speed = Some(1.0);
+        rotation.target = Quat::from_rotation_z(3.0);
+        let current = rotation.drive(0.5);
+        assert!((current.angle_between(Quat::IDENTITY) - 0.5).abs() < 1e-4);
+    }
+
+    #[test]
+    fn compose_into_affine2() {
+        let mut rotation = TransformComponent::new_angle_wrapped(0.1);
+        let mut position = TransformComponent::new_translate(Vec2::ZERO);
+        rotation.target = 6.2;
+        position.target = Vec2::X;
+
+        let transform = rotation.begin(Affine2::from_angle)
+            .and_then(&mut position, Affine2::from_translation)
+            .drive(0.016);
+
+        // The angle turns backwards through zero rather than sweeping forwards.
+        assert!(rotation.current < 0.1);
+        let expected = Affine2::from_translation(position.current) * Affine2::from_angle(rotation.current);
+        assert!(transform.abs_diff_eq(expected, 1e-6));
+    }
+
+    #[test]
+    fn rotate_stays_finite_between_nearly_opposite_rotations() {
+        let mut rotate = TransformComponent::new_rotate(Quat::from_rotation_x(0.0));
+        rotate.target = Quat::from_rotation_x(core::f32::consts::PI - 1e-6);
+
+        for _ in 0..300 {
+            let driven = rotate.drive(0.016);
+            assert!(driven.is_finite() && driven.is_normalized());
+        }
+        assert!(rotate.current.angle_between(rotate.target) < 1e-2);
+    }
+
+    #[test]
+    fn drive_fixed_is_independent_of_cadence() {
+        let mut slow = TransformComponent::new_translate(Vec3::ZERO);
+        slow.target = Vec3::new(3.0, -1.0, 2.0);
+        let mut fast = slow;
+        let mut uneven = slow;
+        let step = 1.0 / 128.0;
+
+        for _ in 0..32 {
+            slow.drive_fixed(1.0 / 32.0, step);
+        }
+        for _ in 0..128 {
+            fast.drive_fixed(1.0 / 128.0, step);
+        }
+        for _ in 0..64 {
+            uneven.drive_fixed(1.0 / 256.0, step);
+            uneven.drive_fixed(3.0 / 256.0, step);
+        }
+
+        assert_eq!(slow.current, fast.current);
+        assert_eq!(slow.current, uneven.current);
+    }
+
+    #[test]
+    fn drive_fixed_caps_substeps() {
+        let mut position = TransformComponent::new_translate(0.0f32);
+        position.target = 1.0;
+
+        position.drive_fixed(1e4, 1e-6);
+        assert_eq!(position.fixed_remainder, 0.0);
+        assert!(position.current > 0.0 && position.current < 1.0);
+
+        // Regular steps carry their remainder as before.
+        position.drive_fixed(0.025, 0.01);
+        assert!((position.fixed_remainder - 0.005).abs() < 1e-6);
+    }
+
+    #[test]
+    fn undo_reverts_every_fixed_substep() {
+        let mut position = TransformComponent::new_translate(0.0f32);
+        position.target = 1.0;
+
+        position.drive_fixed(0.05, 0.01);
+        assert!(position.current > 0.1);
+        position.undo_last_drive();
+        assert_eq!(position.current, 0.0);
+    }
+
+    #[test]
+    fn chain_drive_fixed_carries_remainder() {
+        let mut position = TransformComponent::new_translate(Vec3::ZERO);
+        let mut rotation = TransformComponent::new_rotate(Quat::IDENTITY);
+        position.target = Vec3::X;
+        rotation.target = Quat::from_rotation_y(1.0);
+
+        // Too short for a single step, so nothing moves yet.
+        let transform = position.begin(Mat4::from_translation)
+            .and_then(&mut rotation, Mat4::from_quat)
+            .drive_fixed(0.005, 0.01);
+        assert_eq!(transform, Mat4::IDENTITY);
+
+        let transform = position.begin(Mat4::from_translation)
+            .and_then(&mut rotation, Mat4::from_quat)
+            .drive_fixed(0.005, 0.01);
+        assert_ne!(transform, Mat4::IDENTITY);
+        assert_eq!(transform, Mat4::from_quat(rotation.current) * Mat4::from_translation(position.current));
+    }
+
+    #[test]
+    fn drive_raw_matches_drive() {
+        let mut rotation = TransformComponent::new_rotate(Quat::IDENTITY);
+        let mut position = TransformComponent::new_translate(Vec3::ZERO);
+        rotation.target = Quat::from_rotation_z(1.0);
+        position.target = Vec3::Y;
+        let (mut rotation_copy, mut position_copy) = (rotation, position);
+
+        let (driven_rotation, driven_position) = rotation.begin(Mat4::from_quat)
+            .and_then(&mut position, Mat4::from_translation)
+            .in_parent(Mat4::from_scale(Vec3::splat(2.0)))
+            .drive_raw(0.016);
+        assert_eq!(driven_rotation, rotation_copy.drive(0.016));
+        assert_eq!(driven_position, position_copy.drive(0.016));
+    }
+
+    #[test]
+    fn and_then_before_flips_multiplication() {
+        let mut zoom = TransformComponent::new_zoom(2.0);
+        let mut rotation = TransformComponent::new_rotate(Quat::from_rotation_x(0.4));
+        let mut position = TransformComponent::new_translate(Vec3::new(1.0, 2.0, 3.0));
+        let scale = |zoom| Mat4::from_scale(Vec3::splat(zoom));
+
+        let forwards = zoom.begin(scale)
+            .and_then(&mut rotation, Mat4::from_quat)
+            .and_then(&mut position, Mat4::from_translation)
+            .drive(0.0);
+        let backwards = position.begin(Mat4::from_translation)
+            .and_then_before(&mut rotation, Mat4::from_quat)
+            .and_then_before(&mut zoom, scale)
+            .drive(0.0);
+
+        let expected = Mat4::from_translation(position.current) * Mat4::from_quat(rotation.current) * scale(zoom.current);
+        assert!(forwards.abs_diff_eq(expected, 1e-6));
+        assert!(backwards.abs_diff_eq(expected, 1e-6));
+    }
+
+    #[test]
+    fn set_target_and_snap_are_separate() {
+        let mut position = TransformComponent::new_translate(Vec3::ZERO);
+        position.set_target(Vec3::X);
+        assert_eq!(position.current, Vec3::ZERO);
+
+        position.drive(0.016);
+        position.snap_to_target();
+        assert_eq!(position.current, Vec3::X);
+        assert_eq!(position.target, Vec3::X);
+    }
+
+    #[test]
+    fn time_to_settle_matches_driving() {
+        let mut position = TransformComponent::new_translate(Vec3::ZERO);
+        position.target = Vec3::X * 4.0;
+        assert_eq!(position.remaining(), 4.0);
+
+        let time = position.time_to_settle(0.01);
+        position.drive(time);
+        assert!((position.remaining() - 0.01).abs() < 1e-5);
+        assert_eq!(position.time_to_settle(0.02), 0.0);
+
+        position.retention = 1.0;
+        assert_eq!(position.time_to_settle(0.001), f32::INFINITY);
+        position.retention = 0.0;
+        assert_eq!(position.time_to_settle(0.001), 0.0);
+        position.retention = -0.5;
+        assert_eq!(position.time_to_settle(0.001), 0.0);
+    }
+
+    #[test]
+    fn scale_remaining_is_finite_from_zero() {
+        let mut zoom = TransformComponent::<Scale>::with_state(0.03, 0.0, 1.0);
+        assert!(zoom.remaining().is_finite());
+        assert!(zoom.time_to_settle(1e-3).is_finite());
+
+        for _ in 0..2000 {
+            zoom.drive(0.016);
+        }
+        assert!(zoom.is_settled(1e-3));
+
+        let scale = TransformComponent::<Scale<Vec3>>::with_state(0.03, Vec3::new(1.0, 0.0, 2.0), Vec3::ONE);
+        assert!(scale.remaining().is_finite());
+        assert_eq!(scale.validate(), Err(StabilityError::NonFiniteCurrent));
+    }
 }
@@ -0,0 +1,90 @@
+use crate::{Scalar, Smoothed, TransformComponent};
+
+/// Animates the `retention` of a component from `start` to `end` over
+/// `duration` seconds while driving it.
+///
+/// This lets a follow grab tightly at first and then loosen, or vice versa.
+///
+/// ## Frame rate independence
+/// The retention is only updated once per drive, and the whole step uses
+/// the retention at the end of that step. The exponential step itself stays
+/// frame rate independent, but while the ramp is running larger frames
+/// sample it more coarsely, so trajectories at different frame rates agree
+/// only approximately until the ramp has finished.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RetentionRamp {
+    /// The retention at the start of the ramp.
+    pub start: f32,
+    /// The retention once the ramp has finished.
+    pub end: f32,
+    /// How long the ramp takes, in seconds.
+    pub duration: f32,
+    elapsed: f32,
+}
+
+impl RetentionRamp {
+    /// Creates a new ramp from `start` to `end` over `duration` seconds.
+    pub fn new(start: f32, end: f32, duration: f32) -> Self {
+        Self {
+            start,
+            end,
+            duration,
+            elapsed: 0.0,
+        }
+    }
+
+    /// The retention the ramp is currently at.
+    pub fn retention(&self) -> f32 {
+        let progress = if self.duration > 0.0 {
+            (self.elapsed / self.duration).min(1.0)
+        } else {
+            1.0
+        };
+        self.start + (self.end - self.start) * progress
+    }
+
+    /// Whether the ramp has reached `end`.
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+
+    /// Restarts the ramp from `start`.
+    pub fn restart(&mut self) {
+        self.elapsed = 0.0;
+    }
+
+    /// Advances the ramp by `delta_time` seconds, applies the resulting
+    /// retention to `component`, and then drives it.
+    pub fn drive<T: Smoothed>(&mut self, component: &mut TransformComponent<T>, delta_time: f32) -> T::Attribute {
+        self.elapsed = (self.elapsed + delta_time).min(self.duration.max(0.0));
+        component.retention = T::Scalar::from_f32(self.retention());
+        component.drive(delta_time)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use glam::Vec3;
+    use crate::{Translate, TransformComponent};
+    use super::*;
+
+    #[test]
+    fn ramp_loosens_follow() {
+        let mut ramp = RetentionRamp::new(0.001, 0.05, 1.0);
+        let mut follow = TransformComponent::<Translate<Vec3>>::new(0.5, Vec3::ZERO);
+        let delta_time = 0.02;
+
+        let mut covered = Vec::new();
+        for _ in 0..60 {
+            follow.target += Vec3::X;
+            let previous = follow.current;
+            let driven = ramp.drive(&mut follow, delta_time);
+            covered.push((driven.x - previous.x) / (follow.target.x - previous.x));
+        }
+
+        assert!(ramp.is_finished());
+        assert_eq!(follow.retention, 0.05);
+        assert!(covered[..50].windows(2).all(|pair| pair[1] < pair[0]));
+        assert!((covered[59] - (1.0 - 0.05f32.powf(delta_time))).abs() < 1e-5);
+    }
+}
@@ -0,0 +1,227 @@
+use core::ops::{Add, Mul, Sub};
+use crate::{fixed_steps, DrivenValue, First, Magnitude, Stage};
+
+/// A critically damped spring, which follows its target like Unity's
+/// `SmoothDamp`.
+///
+/// Unlike exponential smoothing, the spring carries a `velocity`, so it
+/// accelerates out of rest and arrives a little more crisply. `smooth_time`
+/// is roughly the time in seconds it takes to reach the target. The target
+/// is never overshot; should a step pass it, the spring stops on it.
+///
+/// Springs are a [`Stage`], so they can be mixed with exponentially
+/// smoothed components in a [`Scaffold`](crate::Scaffold) chain.
+#[derive(Copy, Clone, Debug)]
+pub struct Spring<T> {
+    /// Roughly how long in seconds it takes to reach the target.
+    pub smooth_time: f32,
+    /// The current value.
+    pub current: T,
+    /// The target value.
+    pub target: T,
+    /// The current rate of change, per second.
+    pub velocity: T,
+    remainder: f32,
+}
+
+impl<T> Spring<T>
+where T: Add<T, Output = T> + Mul<f32, Output = T> + Sub<T, Output = T> + Magnitude + Default + Copy {
+    /// Creates a new `Spring` resting at `initial`.
+    pub fn new(smooth_time: f32, initial: T) -> Self {
+        Self {
+            smooth_time,
+            current: initial,
+            target: initial,
+            velocity: T::default(),
+            remainder: 0.0,
+        }
+    }
+
+    /// Sets `target`, leaving the rest of the spring as is.
+    pub fn set_target(&mut self, target: T) {
+        self.target = target;
+    }
+
+    /// Moves only `current`, leaving `target` and `velocity` alone, so that
+    /// a correction blends into the existing motion instead of resetting
+    /// its momentum.
+    pub fn nudge_current(&mut self, new_current: T) {
+        self.current = new_current;
+    }
+
+    /// Whether `current` has come within `epsilon` of `target`.
+    ///
+    /// The velocity isn't considered, so a spring passing quickly through
+    /// its target counts as settled for that moment.
+    pub fn is_settled(&self, epsilon: f32) -> bool {
+        (self.target - self.current).magnitude() <= epsilon
+    }
+
+    /// Returns what [`drive`](Self::drive) would produce after `delta_time`
+    /// seconds, without modifying the spring.
+    pub fn peek(&self, delta_time: f32) -> T {
+        let mut copy = *self;
+        copy.drive(delta_time)
+    }
+
+    /// Interpolates linearly from `current` towards `target` by `t`,
+    /// without modifying the spring.
+    pub fn at_fraction(&self, t: f32) -> T {
+        self.current + (self.target - self.current) * t
+    }
+
+    /// Begins a transformation, like [`TransformComponent::begin`](crate::TransformComponent::begin).
+    pub fn begin<O, F: FnOnce(T) -> O>(&mut self, f: F) -> First<'_, Self, F, O> {
+        First::new(self, f)
+    }
+
+    /// Drives the spring by `delta_time` seconds.
+    ///
+    /// This uses the usual approximation of `exp(-ω·dt)` through a rational
+    /// polynomial, with `ω = 2 / smooth_time`, which is stable for any
+    /// `delta_time`.
+    pub fn drive(&mut self, delta_time: f32) -> T {
+        if delta_time <= 0.0 {
+            return self.current;
+        }
+
+        let omega = 2.0 / self.smooth_time.max(1e-4);
+        let x = omega * delta_time;
+        let decay = 1.0 / (1.0 + x + 0.48 * x * x + 0.235 * x * x * x);
+
+        let change = self.current - self.target;
+        let temp = (self.velocity + change * omega) * delta_time;
+        let next = self.target + (change + temp) * decay;
+
+        // Only snap once the step actually crossed the target.
+        if (self.target - self.current).dot(next - self.target) > 0.0 {
+            self.current = self.target;
+            self.velocity = T::default();
+        } else {
+            self.velocity = (self.velocity - temp * omega) * decay;
+            self.current = next;
+        }
+
+        self.current
+    }
+
+    /// Drives the spring in fixed substeps of `step` seconds, like
+    /// [`TransformComponent::drive_fixed`](crate::TransformComponent::drive_fixed).
+    pub fn drive_fixed(&mut self, delta_time: f32, step: f32) -> T {
+        if step.is_nan() || step <= 0.0 {
+            return self.drive(delta_time);
+        }
+
+        for _ in 0..fixed_steps(&mut self.remainder, delta_time, step) {
+            self.drive(step);
+        }
+        self.current
+    }
+}
+
+impl<T> Stage for Spring<T>
+where T: Add<T, Output = T> + Mul<f32, Output = T> + Sub<T, Output = T> + Magnitude + Default + Copy {
+    type Attribute = T;
+    #[inline(always)]
+    fn drive(&mut self, delta_time: f32) -> T {
+        Spring::drive(self, delta_time)
+    }
+    #[inline(always)]
+    fn peek(&self, delta_time: f32) -> T {
+        Spring::peek(self, delta_time)
+    }
+    #[inline(always)]
+    fn at_fraction(&self, t: f32) -> T {
+        Spring::at_fraction(self, t)
+    }
+    #[inline(always)]
+    fn drive_fixed(&mut self, delta_time: f32, step: f32) -> T {
+        Spring::drive_fixed(self, delta_time, step)
+    }
+    #[inline(always)]
+    fn is_settled(&self, epsilon: f32) -> bool {
+        Spring::is_settled(self, epsilon)
+    }
+    #[inline(always)]
+    fn driven_value(value: T) -> DrivenValue {
+        value.driven_value()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use glam::{Mat4, Quat, Vec2, Vec3};
+    use crate::{Scaffold, TransformComponent};
+    use super::*;
+
+    #[test]
+    fn converges_without_overshoot() {
+        let mut spring = Spring::new(0.3, Vec2::ZERO);
+        spring.target = Vec2::new(3.0, 4.0);
+
+        let mut previous = 0.0;
+        for _ in 0..200 {
+            let distance = spring.drive(0.016).length();
+            assert!(distance >= previous && distance <= 5.0 + 1e-5);
+            previous = distance;
+        }
+        assert!(spring.current.abs_diff_eq(spring.target, 1e-3));
+    }
+
+    #[test]
+    fn mixes_with_components_in_a_chain() {
+        let mut position = Spring::new(0.2, Vec3::ZERO);
+        let mut rotation = TransformComponent::new_rotate(Quat::IDENTITY);
+        position.target = Vec3::X;
+        rotation.target = Quat::from_rotation_y(1.0);
+
+        let mut reference = position;
+        let predicted = rotation.begin(Mat4::from_quat)
+            .and_then(&mut position, Mat4::from_translation)
+            .peek(0.016);
+        let transform = rotation.begin(Mat4::from_quat)
+            .and_then(&mut position, Mat4::from_translation)
+            .drive(0.016);
+
+        assert_eq!(predicted, transform);
+        assert_eq!(position.current, reference.drive(0.016));
+        assert_eq!(transform, Mat4::from_translation(position.current) * Mat4::from_quat(rotation.current));
+        assert_ne!(position.velocity, Vec3::ZERO);
+    }
+
+    #[test]
+    fn nudge_keeps_velocity() {
+        let mut spring = Spring::new(0.3, 0.0_f32);
+        spring.set_target(10.0);
+        spring.drive(0.1);
+        let velocity = spring.velocity;
+
+        spring.nudge_current(2.0);
+        assert_eq!(spring.current, 2.0);
+        assert_eq!(spring.target, 10.0);
+        assert_eq!(spring.velocity, velocity);
+    }
+
+    #[test]
+    fn target_reversing_keeps_momentum() {
+        let mut spring = Spring::new(0.3, 0.0_f32);
+        spring.target = 5.0;
+        for _ in 0..10 {
+            spring.drive(0.016);
+        }
+        assert!(spring.velocity > 5.0);
+
+        // The target jumps just behind the moving spring.
+        spring.target = spring.current - 0.1;
+        let before = spring.current;
+        spring.drive(0.016);
+        assert_ne!(spring.current, spring.target);
+        assert!(spring.current > before);
+        assert!(spring.velocity != 0.0);
+
+        for _ in 0..500 {
+            spring.drive(0.016);
+        }
+        assert!(spring.is_settled(1e-3));
+    }
+}
@@ -0,0 +1,132 @@
+use core::fmt::Formatter;
+use crate::{Scalar, Smoothed};
+
+/// Eases through a sequence of keyframes on a fixed schedule, regardless
+/// of whether the value has settled on any of them.
+///
+/// Each keyframe holds a value and the duration in seconds it takes to
+/// get there from the previous one, or from `start` for the first. Within
+/// a segment the attribute's own interpolation is used, eased in and out
+/// through a smoothstep, so the value arrives on each keyframe exactly at
+/// its scheduled time. After the last keyframe the value is held.
+/// ```
+/// # use glam::Vec3;
+/// # use buttery::{Timeline, Translate};
+/// let mut timeline = Timeline::<Translate<Vec3>>::new(Vec3::ZERO);
+/// timeline.push(Vec3::X, 1.0);
+/// timeline.push(Vec3::Y, 0.5);
+///
+/// assert_eq!(timeline.drive(1.0), Vec3::X);
+/// assert_eq!(timeline.drive(0.5), Vec3::Y);
+/// assert!(timeline.is_finished());
+/// ```
+pub struct Timeline<T: Smoothed> {
+    /// The value before the first keyframe.
+    pub start: T::Attribute,
+    keyframes: Vec<(T::Attribute, f32)>,
+    elapsed: f32,
+}
+
+impl<T: Smoothed> core::fmt::Debug for Timeline<T> where
+    T::Attribute: core::fmt::Debug {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Timeline")
+            .field("start", &self.start)
+            .field("keyframes", &self.keyframes)
+            .field("elapsed", &self.elapsed)
+            .finish()
+    }
+}
+
+impl<T: Smoothed> Timeline<T> {
+    /// Creates an empty timeline resting at `start`.
+    pub fn new(start: T::Attribute) -> Self {
+        Self {
+            start,
+            keyframes: Vec::new(),
+            elapsed: 0.0,
+        }
+    }
+
+    /// Appends a keyframe reached `duration` seconds after the previous one.
+    pub fn push(&mut self, value: T::Attribute, duration: f32) {
+        self.keyframes.push((value, duration.max(0.0)));
+    }
+
+    /// The keyframes, as `(value, duration)` pairs.
+    pub fn keyframes(&self) -> &[(T::Attribute, f32)] {
+        &self.keyframes
+    }
+
+    /// The total duration of every keyframe, in seconds.
+    pub fn duration(&self) -> f32 {
+        self.keyframes.iter().map(|&(_, duration)| duration).sum()
+    }
+
+    /// How far into the timeline playback is, in seconds.
+    pub fn elapsed(&self) -> f32 {
+        self.elapsed
+    }
+
+    /// Whether the last keyframe has been reached.
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration()
+    }
+
+    /// Restarts playback from `start`.
+    pub fn restart(&mut self) {
+        self.elapsed = 0.0;
+    }
+
+    /// The value at the current point of playback.
+    pub fn value(&self) -> T::Attribute {
+        let mut from = self.start;
+        let mut remaining = self.elapsed;
+        for &(to, duration) in &self.keyframes {
+            if remaining < duration {
+                let t = remaining / duration;
+                let eased = t * t * (3.0 - 2.0 * t);
+                return T::drive(T::align(to, from), from, T::Scalar::from_f32(eased));
+            }
+            remaining -= duration;
+            from = to;
+        }
+        from
+    }
+
+    /// Advances playback by `delta_time` seconds, returning the new value.
+    pub fn drive(&mut self, delta_time: f32) -> T::Attribute {
+        self.elapsed = (self.elapsed + delta_time).min(self.duration());
+        self.value()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Translate;
+    use super::*;
+
+    #[test]
+    fn reaches_keyframes_on_schedule() {
+        let mut timeline = Timeline::<Translate<f32>>::new(0.0);
+        timeline.push(1.0, 0.5);
+        timeline.push(-2.0, 1.0);
+        timeline.push(4.0, 0.25);
+
+        let mut values = Vec::new();
+        for _ in 0..7 {
+            values.push(timeline.drive(0.25));
+        }
+
+        assert_eq!(values[1], 1.0);
+        assert_eq!(values[5], -2.0);
+        assert_eq!(values[6], 4.0);
+        assert_eq!(values[0], 0.5);
+        assert!(values[2] < 1.0 && values[2] > -2.0);
+        assert!(timeline.is_finished());
+        assert_eq!(timeline.drive(1.0), 4.0);
+
+        timeline.restart();
+        assert_eq!(timeline.value(), 0.0);
+    }
+}
@@ -0,0 +1,270 @@
+use core::fmt::Formatter;
+use glam::{Mat4, Quat, Vec3};
+use crate::{Rotate, Scaffold, Scale, Smoothed, TransformComponent, Translate};
+
+/// A bundled translation, rotation and scale, driven together into
+/// a single matrix.
+///
+/// The scale channel interpolates linearly by default. See
+/// [`with_log_scale`](Self::with_log_scale) to interpolate it geometrically.
+pub struct Transform<S: Smoothed<Attribute = Vec3> = Translate<Vec3>> {
+    /// The smoothed translation.
+    pub translation: TransformComponent<Translate<Vec3>>,
+    /// The smoothed rotation.
+    pub rotation: TransformComponent<Rotate>,
+    /// The smoothed scale.
+    pub scale: TransformComponent<S>,
+}
+
+impl<S: Smoothed<Attribute = Vec3>> core::fmt::Debug for Transform<S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Transform")
+            .field("translation", &self.translation)
+            .field("rotation", &self.rotation)
+            .field("scale", &self.scale)
+            .finish()
+    }
+}
+
+impl Transform {
+    /// Creates a new `Transform` using the retentions of
+    /// [`new_translate`](TransformComponent::new_translate),
+    /// [`new_rotate`](TransformComponent::new_rotate) and
+    /// [`new_zoom`](TransformComponent::new_zoom) respectively.
+    pub fn new(translation: Vec3, rotation: Quat, scale: Vec3) -> Self {
+        Self {
+            translation: TransformComponent::new_translate(translation),
+            rotation: TransformComponent::new_rotate(rotation),
+            scale: TransformComponent::new_zoom(scale),
+        }
+    }
+
+    /// Makes the scale channel interpolate in log space through [`Scale`],
+    /// so that equal amounts of time produce equal multiplicative changes.
+    ///
+    /// Translation and rotation are unaffected. Every component of the
+    /// scale, both current and target, must be strictly positive.
+    pub fn with_log_scale(self) -> Transform<Scale<Vec3>> {
+        Transform {
+            translation: self.translation,
+            rotation: self.rotation,
+            scale: self.scale.reinterpret(),
+        }
+    }
+}
+
+impl<S: Smoothed<Attribute = Vec3>> Transform<S> {
+    /// Drives every channel by `delta_time` seconds, returning the
+    /// matrix which scales, then rotates, then translates.
+    pub fn drive(&mut self, delta_time: f32) -> Mat4 {
+        let translation = self.translation.drive(delta_time);
+        let rotation = self.rotation.drive(delta_time);
+        let scale = self.scale.drive(delta_time);
+        Mat4::from_scale_rotation_translation(scale, rotation, translation)
+    }
+}
+
+/// A bundled position and orientation, such as for a camera, driven
+/// together into a single rigid matrix.
+///
+/// Unlike [`Transform`] there is no scale channel. Both channels are
+/// exposed, so their targets and retentions can be adjusted independently:
+/// ```
+/// # use glam::{Quat, Vec3};
+/// # use buttery::RigidTransform;
+/// let mut camera = RigidTransform::new(Vec3::ZERO, Quat::IDENTITY);
+/// camera.position.target = Vec3::X;
+/// camera.rotation.target = Quat::from_rotation_y(0.3);
+///
+/// let view_matrix = camera.drive(0.016).inverse();
+/// # assert!(view_matrix.is_finite());
+/// ```
+#[derive(Debug)]
+pub struct RigidTransform {
+    /// The smoothed position.
+    pub position: TransformComponent<Translate<Vec3>>,
+    /// The smoothed orientation.
+    pub rotation: TransformComponent<Rotate>,
+}
+
+impl RigidTransform {
+    /// Creates a new `RigidTransform` using the retentions of
+    /// [`new_translate`](TransformComponent::new_translate) and
+    /// [`new_rotate`](TransformComponent::new_rotate) respectively.
+    pub fn new(position: Vec3, rotation: Quat) -> Self {
+        Self {
+            position: TransformComponent::new_translate(position),
+            rotation: TransformComponent::new_rotate(rotation),
+        }
+    }
+
+    /// Drives both channels by `delta_time` seconds, returning the matrix
+    /// which rotates, then translates.
+    pub fn drive(&mut self, delta_time: f32) -> Mat4 {
+        let position = self.position.drive(delta_time);
+        let rotation = self.rotation.drive(delta_time);
+        Mat4::from_rotation_translation(rotation, position)
+    }
+}
+
+/// Smooths a whole matrix which only becomes known each frame, for example
+/// from IK or physics, by smoothing its decomposed scale, rotation and
+/// translation through a [`Transform`].
+///
+/// ## Failure mode
+/// Targets are decomposed with [`Mat4::to_scale_rotation_translation`], so
+/// they must be affine and free of shear. Shear is silently lost, and the
+/// projective row of projection matrices is ignored, so the smoothed matrix
+/// will not converge onto such targets.
+#[derive(Debug)]
+pub struct SmoothMatrix {
+    /// The decomposed channels being smoothed.
+    pub transform: Transform,
+}
+
+impl SmoothMatrix {
+    /// Creates a new `SmoothMatrix` resting at `initial`, with the
+    /// retentions of [`Transform::new`].
+    pub fn new(initial: Mat4) -> Self {
+        let (scale, rotation, translation) = initial.to_scale_rotation_translation();
+        Self {
+            transform: Transform::new(translation, rotation, scale),
+        }
+    }
+
+    /// Decomposes `target` and smooths towards it from now on.
+    pub fn set_target(&mut self, target: Mat4) {
+        let (scale, rotation, translation) = target.to_scale_rotation_translation();
+        self.transform.translation.target = translation;
+        self.transform.rotation.target = rotation;
+        self.transform.scale.target = scale;
+    }
+
+    /// The matrix currently being smoothed towards.
+    pub fn target(&self) -> Mat4 {
+        Mat4::from_scale_rotation_translation(
+            self.transform.scale.target,
+            self.transform.rotation.target,
+            self.transform.translation.target,
+        )
+    }
+
+    /// Drives the smoothed matrix by `delta_time` seconds.
+    pub fn drive(&mut self, delta_time: f32) -> Mat4 {
+        self.transform.drive(delta_time)
+    }
+}
+
+/// Remembers the matrix a chain produced when it was last driven.
+///
+/// Driving a chain again just to read its matrix would advance every
+/// component a second time. Instead, drive it through this once per frame,
+/// and read [`last_matrix`](Self::last_matrix) wherever else it's needed:
+/// ```
+/// # use glam::{Mat4, Vec3};
+/// # use buttery::{CachedTransform, TransformComponent};
+/// let mut position = TransformComponent::new_translate(Vec3::ZERO);
+/// let mut cache = CachedTransform::default();
+///
+/// position.target = Vec3::X;
+/// let world = cache.drive(position.begin(Mat4::from_translation), 0.016);
+/// assert_eq!(cache.last_matrix(), world);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CachedTransform {
+    last_matrix: Mat4,
+}
+
+impl Default for CachedTransform {
+    fn default() -> Self {
+        Self::new(Mat4::IDENTITY)
+    }
+}
+
+impl CachedTransform {
+    /// Creates a new `CachedTransform` which reports `initial` until
+    /// it is first driven.
+    pub const fn new(initial: Mat4) -> Self {
+        Self {
+            last_matrix: initial,
+        }
+    }
+
+    /// Drives `chain` by `delta_time` seconds, and remembers the result.
+    pub fn drive<S: Scaffold<Output = Mat4>>(&mut self, chain: S, delta_time: f32) -> Mat4 {
+        self.last_matrix = chain.drive(delta_time);
+        self.last_matrix
+    }
+
+    /// The matrix returned by the most recent [`drive`](Self::drive).
+    pub fn last_matrix(&self) -> Mat4 {
+        self.last_matrix
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn log_scale_midpoint_is_geometric() {
+        let mut linear = Transform::new(Vec3::ZERO, Quat::IDENTITY, Vec3::ONE);
+        let mut log = Transform::new(Vec3::ZERO, Quat::IDENTITY, Vec3::ONE).with_log_scale();
+        linear.scale.target = Vec3::splat(100.0);
+        log.scale.target = Vec3::splat(100.0);
+
+        // Time after which exactly half the remaining distance is covered.
+        let half = 0.5f32.ln() / linear.scale.retention.ln();
+        let linear_matrix = linear.drive(half);
+        let log_matrix = log.drive(half);
+
+        assert!(linear.scale.current.abs_diff_eq(Vec3::splat(50.5), 1e-3));
+        assert!(log.scale.current.abs_diff_eq(Vec3::splat(10.0), 1e-3));
+        assert!(linear_matrix.abs_diff_eq(Mat4::from_scale(Vec3::splat(50.5)), 1e-3));
+        assert!(log_matrix.abs_diff_eq(Mat4::from_scale(Vec3::splat(10.0)), 1e-3));
+    }
+
+    #[test]
+    fn smooth_matrix_converges_on_rigid_target() {
+        let mut smooth = SmoothMatrix::new(Mat4::IDENTITY);
+        let target = Mat4::from_rotation_translation(Quat::from_rotation_y(2.0) * Quat::from_rotation_x(0.3), Vec3::new(4.0, -1.0, 2.0));
+        smooth.set_target(target);
+        assert!(smooth.target().abs_diff_eq(target, 1e-5));
+
+        let first = smooth.drive(0.016);
+        assert!(!first.abs_diff_eq(target, 1e-2));
+
+        for _ in 0..600 {
+            smooth.drive(0.016);
+        }
+        assert!(smooth.drive(0.016).abs_diff_eq(target, 1e-4));
+    }
+
+    #[test]
+    fn cached_matrix_waits_for_next_drive() {
+        let mut position = TransformComponent::new_translate(Vec3::ZERO);
+        let mut cache = CachedTransform::new(Mat4::IDENTITY);
+        position.target = Vec3::X;
+
+        let driven = cache.drive(position.begin(Mat4::from_translation), 0.016);
+        assert_eq!(cache.last_matrix(), driven);
+
+        position.target = Vec3::NEG_Y * 10.0;
+        assert_eq!(cache.last_matrix(), driven);
+
+        let next = cache.drive(position.begin(Mat4::from_translation), 0.016);
+        assert_ne!(next, driven);
+        assert_eq!(cache.last_matrix(), next);
+    }
+
+    #[test]
+    fn rigid_rotates_then_translates() {
+        let mut rigid = RigidTransform::new(Vec3::ZERO, Quat::IDENTITY);
+        rigid.position.target = Vec3::new(1.0, 2.0, 3.0);
+        rigid.rotation.target = Quat::from_rotation_y(1.0);
+
+        let matrix = rigid.drive(0.1);
+        let expected = Mat4::from_translation(rigid.position.current) * Mat4::from_quat(rigid.rotation.current);
+        assert!(matrix.abs_diff_eq(expected, 1e-6));
+    }
+}
@@ -0,0 +1,136 @@
+use glam::{Affine2, Vec2};
+use crate::{Rotate2D, Spring, TransformComponent, Translate};
+
+/// A bundled 2D position, rotation and uniform scale, driven together
+/// into an [`Affine2`] for animating UI panels and other screen space items.
+///
+/// The resulting transform scales first, then rotates, then translates:
+/// ```
+/// # use glam::{Affine2, Vec2};
+/// # use buttery::Ui2D;
+/// let mut panel = Ui2D::new(Vec2::new(-200.0, 0.0), 0.0, 0.0);
+///
+/// // Slide the panel in while popping it up to full size.
+/// panel.position.target = Vec2::new(40.0, 40.0);
+/// panel.scale.target = 1.0;
+///
+/// for _ in 0..240 {
+///     let transform: Affine2 = panel.drive(0.016);
+///     # assert!(transform.is_finite());
+/// }
+/// # assert!(panel.position.current.abs_diff_eq(Vec2::new(40.0, 40.0), 1e-3));
+/// ```
+#[derive(Debug)]
+pub struct Ui2D {
+    /// The smoothed position.
+    pub position: TransformComponent<Translate<Vec2>>,
+    /// The smoothed rotation, in radians.
+    pub rotation: TransformComponent<Rotate2D>,
+    /// The smoothed uniform scale.
+    pub scale: TransformComponent<Translate<f32>>,
+}
+
+impl Ui2D {
+    /// Creates a new `Ui2D` using the retentions of
+    /// [`new_translate`](TransformComponent::new_translate),
+    /// [`new_angle_wrapped`](TransformComponent::<Rotate2D>::new_angle_wrapped) and
+    /// [`new_zoom`](TransformComponent::new_zoom) respectively.
+    pub fn new(position: Vec2, rotation: f32, scale: f32) -> Self {
+        Self {
+            position: TransformComponent::new_translate(position),
+            rotation: TransformComponent::new_angle_wrapped(rotation),
+            scale: TransformComponent::new_zoom(scale),
+        }
+    }
+
+    /// Drives every channel by `delta_time` seconds, returning the
+    /// transform which scales, then rotates, then translates.
+    pub fn drive(&mut self, delta_time: f32) -> Affine2 {
+        let position = self.position.drive(delta_time);
+        let rotation = self.rotation.drive(delta_time);
+        let scale = self.scale.drive(delta_time);
+        Affine2::from_scale_angle_translation(Vec2::splat(scale), rotation, position)
+    }
+}
+
+/// A [`Spring`] for UI elements sliding open or closed, which snaps onto
+/// its target and reports when it has finished.
+///
+/// This avoids both the overshoot of an underdamped spring and the endless
+/// tail of exponential smoothing. Once the distance to the target is below
+/// `epsilon` and the speed below `velocity_epsilon`, the value is snapped
+/// onto the target and the animation counts as finished.
+#[derive(Copy, Clone, Debug)]
+pub struct UiSpring {
+    /// The underlying spring.
+    pub spring: Spring<f32>,
+    /// How close the value must be to the target to finish.
+    pub epsilon: f32,
+    /// How slow the value must be moving to finish.
+    pub velocity_epsilon: f32,
+}
+
+impl UiSpring {
+    /// Creates a new `UiSpring` resting at `initial`, with epsilons
+    /// suited to values measured in pixels.
+    pub fn new(smooth_time: f32, initial: f32) -> Self {
+        Self {
+            spring: Spring::new(smooth_time, initial),
+            epsilon: 0.01,
+            velocity_epsilon: 0.1,
+        }
+    }
+
+    /// Drives the spring by `delta_time` seconds, returning the value and
+    /// whether it has finished settling on the target.
+    pub fn drive(&mut self, delta_time: f32) -> (f32, bool) {
+        let value = self.spring.drive(delta_time);
+        let finished = (self.spring.target - value).abs() < self.epsilon
+            && self.spring.velocity.abs() < self.velocity_epsilon;
+        if finished {
+            self.spring.current = self.spring.target;
+            self.spring.velocity = 0.0;
+        }
+        (self.spring.current, finished)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::f32::consts::FRAC_PI_2;
+    use super::*;
+
+    #[test]
+    fn composes_scale_rotation_translation() {
+        let mut panel = Ui2D::new(Vec2::new(10.0, 0.0), FRAC_PI_2, 2.0);
+        let transform = panel.drive(0.016);
+
+        // (1, 0) scaled to (2, 0), rotated to (0, 2), translated to (10, 2).
+        let point = transform.transform_point2(Vec2::X);
+        assert!(point.abs_diff_eq(Vec2::new(10.0, 2.0), 1e-5));
+    }
+
+    #[test]
+    fn rotation_takes_short_way() {
+        let mut panel = Ui2D::new(Vec2::ZERO, 0.1, 1.0);
+        panel.rotation.target = 6.2;
+
+        let angle = panel.rotation.drive(0.016);
+        assert!(angle < 0.1 && angle > -0.2);
+    }
+
+    #[test]
+    fn ui_spring_finishes_without_overshoot() {
+        let mut panel = UiSpring::new(0.2, -300.0);
+        panel.spring.target = 0.0;
+
+        let finished_after = (1..=200).find(|_| {
+            let (value, finished) = panel.drive(0.016);
+            assert!(value <= 0.0);
+            finished
+        });
+        assert!(finished_after.is_some_and(|frames| frames < 100));
+        assert_eq!(panel.spring.current, 0.0);
+        assert_eq!(panel.drive(0.016), (0.0, true));
+    }
+}